@@ -1,7 +1,9 @@
 import type {
   AutomationWorkspaceSnapshot,
+  DirectoryProbe,
   GitBranchInfo,
   GitDiffResponse,
+  GitDiffStreamEvent,
   GitHubIssueSummary,
   GitHubPrSummary,
   GitHubRunSummary,
@@ -11,14 +13,22 @@ import type {
   KanbanRunLogsResponse,
   KanbanTask,
   KanbanTaskRun,
+  PaletteSearchResult,
+  PaneChangeSet,
   PaneCommandResult,
   PaneEvent,
   PruneWorktreesResponse,
   RepoContext,
   RuntimeStats,
+  ScratchWorktree,
+  ShutdownReport,
   SpawnPaneRequest,
   SpawnPaneResponse,
+  SpawnPaneResult,
+  SpawnPanesRequest,
   WorktreeEntry,
+  WorktreeSnapshot,
+  WorktreeSyncResult,
   WritePaneInputRequest,
 } from "../types";
 
@@ -41,6 +51,7 @@ interface E2eState {
   panes: Map<string, E2ePaneRuntime>;
   worktrees: Map<string, WorktreeEntry>;
   branchByPath: Map<string, string>;
+  scratchWorktrees: Map<string, ScratchWorktree>;
   automationWorkspaces: AutomationWorkspaceSnapshot[];
   kanban: E2eKanbanState;
   runCounter: number;
@@ -109,10 +120,10 @@ const INITIAL_WORKTREES: WorktreeEntry[] = [
 ];
 
 const E2E_GIT_STATUS_FILES: GitStatusSnapshot["files"] = [
-  { path: "apps/desktop/src/lib/tauri.ts", code: "M", staged: true, unstaged: false, untracked: false },
-  { path: "tests/visual/shell-regression.spec.ts", code: "A", staged: true, unstaged: false, untracked: false },
-  { path: "docs/project-status.md", code: "M", staged: false, unstaged: true, untracked: false },
-  { path: "apps/desktop/src/styles.css", code: "M", staged: false, unstaged: true, untracked: false },
+  { path: "apps/desktop/src/lib/tauri.ts", code: "M", staged: true, unstaged: false, untracked: false, additions: 12, deletions: 3 },
+  { path: "tests/visual/shell-regression.spec.ts", code: "A", staged: true, unstaged: false, untracked: false, additions: 40, deletions: 0 },
+  { path: "docs/project-status.md", code: "M", staged: false, unstaged: true, untracked: false, additions: 2, deletions: 1 },
+  { path: "apps/desktop/src/styles.css", code: "M", staged: false, unstaged: true, untracked: false, additions: 6, deletions: 6 },
   { path: "tests/visual/theme-regression.spec.ts-snapshots", code: "??", staged: false, unstaged: false, untracked: true },
 ];
 
@@ -121,6 +132,9 @@ const E2E_GIT_BRANCHES: GitBranchInfo[] = [
     name: "main",
     isCurrent: true,
     upstream: "origin/main",
+    ahead: 0,
+    behind: 0,
+    upstreamGone: false,
     commit: "9f3b1f2",
     subject: "feat(ui): stabilize compact shadcn baseline",
   },
@@ -128,6 +142,9 @@ const E2E_GIT_BRANCHES: GitBranchInfo[] = [
     name: "feature/visual-regression",
     isCurrent: false,
     upstream: "origin/feature/visual-regression",
+    ahead: 2,
+    behind: 0,
+    upstreamGone: false,
     commit: "20bfad1",
     subject: "test(visual): add settings screenshots",
   },
@@ -135,6 +152,9 @@ const E2E_GIT_BRANCHES: GitBranchInfo[] = [
     name: "fix/ci-annotations",
     isCurrent: false,
     upstream: "origin/fix/ci-annotations",
+    ahead: 0,
+    behind: 1,
+    upstreamGone: false,
     commit: "bcde901",
     subject: "ci: upload playwright report on failure",
   },
@@ -235,6 +255,7 @@ function createDefaultState(): E2eState {
     panes: new Map(),
     worktrees: new Map(worktreePairs),
     branchByPath: new Map(branchPairs),
+    scratchWorktrees: new Map(),
     automationWorkspaces: [],
     kanban: {
       tasks: new Map(),
@@ -354,6 +375,23 @@ function resolveRepo(cwd: string): RepoContext {
   };
 }
 
+function syncWorktreeResult(worktreePath: string, strategy: unknown): WorktreeSyncResult {
+  const normalized = normalizePath(worktreePath);
+  return {
+    worktreePath: normalized,
+    branch: resolveBranch(normalized),
+    base: "origin/main",
+    strategy: strategy === "rebase" ? "rebase" : "merge",
+    status: "upToDate",
+    incomingCommits: 0,
+    headBefore: "9f3b1f2",
+    headAfter: "9f3b1f2",
+    conflicts: [],
+    output: "already up to date",
+    error: null,
+  };
+}
+
 function runById(runId: string): KanbanTaskRun {
   const run = state.kanban.runs.get(runId);
   if (!run) {
@@ -372,6 +410,8 @@ function defaultGitStatus(repoRoot: string): GitStatusSnapshot {
     stagedCount: 2,
     unstagedCount: 2,
     untrackedCount: 1,
+    conflictedCount: 0,
+    operation: null,
     files: E2E_GIT_STATUS_FILES.map((file) => ({ ...file })),
   };
 }
@@ -409,6 +449,20 @@ export async function e2eSpawnPane(
   };
 }
 
+export async function e2eSpawnPanes(
+  request: SpawnPanesRequest,
+  emitPaneEvent: (event: PaneEvent) => void,
+): Promise<SpawnPaneResult[]> {
+  const paneIds = request.paneIds
+    ?? Array.from({ length: request.count ?? 0 }, () => `pane-${crypto.randomUUID()}`);
+  return Promise.all(paneIds.map((paneId) => e2eSpawnPane({
+    paneId,
+    cwd: request.cwd,
+    shell: request.shell,
+    initCommand: request.initCommand,
+  }, emitPaneEvent)));
+}
+
 export function e2ePickDirectory(defaultPath?: string): string {
   const normalized = defaultPath?.trim();
   return normalized && normalized.length > 0 ? normalized : E2E_DEFAULT_CWD;
@@ -446,6 +500,38 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
     case "resize_pane":
       return undefined as T;
 
+    case "attach_output_channel":
+    case "detach_output_channel":
+      return undefined as T;
+
+    case "get_pane_size":
+      return {
+        paneId: String(request.paneId ?? ""),
+        rows: 40,
+        cols: 120,
+        resizedAtMs: null,
+        ptyRows: 40,
+        ptyCols: 120,
+      } as T;
+
+    case "get_pane_environment":
+      return {
+        paneId: String(request.paneId ?? ""),
+        pid: Number(request.pid ?? 4242),
+        command: "bash",
+        variables: { HOME: "/home/e2e", PWD: E2E_DEFAULT_CWD, SHELL: "/bin/bash" },
+      } as T;
+
+    case "get_unsent_input":
+      return null as T;
+
+    case "replay_pane_output":
+      return {
+        paneId: String(request.paneId ?? ""),
+        replayedBytes: 0,
+        historyTruncated: false,
+      } as T;
+
     case "close_pane": {
       const paneId = String(request.paneId ?? "");
       state.panes.delete(paneId);
@@ -470,11 +556,49 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
       return undefined as T;
     }
 
+    case "start_pane_change_tracking":
+    case "stop_pane_change_tracking":
+      return undefined as T;
+
+    case "get_pane_change_set": {
+      const paneId = String(request.paneId ?? "");
+      const changeSet: PaneChangeSet = {
+        paneId,
+        worktreePath: state.panes.get(paneId)?.cwd ?? MAIN_WORKTREE_PATH,
+        tracking: true,
+        startedAtMs: Date.parse("2026-02-19T11:00:00Z"),
+        stoppedAtMs: null,
+        files: [
+          { path: "apps/desktop/src/lib/tauri.ts", kind: "modified", changedAtMs: Date.parse("2026-02-19T11:02:00Z") },
+        ],
+        truncated: false,
+      };
+      return changeSet as T;
+    }
+
     case "resolve_repo_context":
       return resolveRepo(String(request.cwd ?? MAIN_WORKTREE_PATH)) as T;
 
+    case "probe_directory": {
+      const repo = resolveRepo(String(request.path ?? MAIN_WORKTREE_PATH));
+      const probe: DirectoryProbe = {
+        path: repo.worktreePath,
+        isGitRepo: true,
+        repoRoot: repo.repoRoot,
+        isLinkedWorktree: repo.worktreePath !== MAIN_WORKTREE_PATH,
+        currentBranch: repo.branch,
+        defaultBranch: "main",
+        remotes: [{ name: "origin", url: "https://example.com/super-vibing.git" }],
+        projectTypes: ["node"],
+        sizeEstimateBytes: 48 * 1024 * 1024,
+        sizeEstimateTruncated: false,
+      };
+      return probe as T;
+    }
+
     case "create_worktree": {
-      const branch = String(request.branch ?? "feature-e2e");
+      const requested = String(request.branch ?? "feature-e2e");
+      const branch = request.mode === "remoteBranch" ? requested.replace(/^origin\//, "") : requested;
       const segment = safeBranchSegment(branch);
       const worktreePath = `${MAIN_REPO_ROOT}/worktrees/${segment}`;
       const entry: WorktreeEntry = {
@@ -497,6 +621,15 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
     case "list_worktrees":
       return Array.from(state.worktrees.values()) as T;
 
+    case "import_external_worktree": {
+      const worktreePath = normalizePath(String(request.path ?? ""));
+      const entry = state.worktrees.get(worktreePath);
+      if (!entry) {
+        throw new Error(`worktree '${worktreePath}' not found`);
+      }
+      return entry as T;
+    }
+
     case "remove_worktree": {
       const worktreePath = normalizePath(String(request.worktreePath ?? ""));
       const entry = state.worktrees.get(worktreePath);
@@ -533,12 +666,54 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
       return response as T;
     }
 
+    case "list_snapshots":
+      return [] as T;
+
+    case "snapshot_worktree":
+    case "restore_snapshot": {
+      const name = String(request.name ?? "wip");
+      const snapshot: WorktreeSnapshot = {
+        name,
+        refName: `refs/supervibing/snapshots/${name}`,
+        commit: "4c1d2e3",
+        sourceWorktreePath: String(request.worktreePath ?? MAIN_WORKTREE_PATH),
+        createdAt: nowIso(),
+      };
+      if (command === "snapshot_worktree") {
+        return snapshot as T;
+      }
+      return {
+        snapshot,
+        worktreePath: String(request.worktreePath ?? MAIN_WORKTREE_PATH),
+        dropped: Boolean(request.dropAfterRestore),
+        output: `restored snapshot ${name}`,
+      } as T;
+    }
+
     case "run_global_command": {
       const paneIds = Array.isArray(request.paneIds) ? request.paneIds.map(String) : [];
       const results: PaneCommandResult[] = paneIds.map((paneId) => ({ paneId, ok: true }));
       return results as T;
     }
 
+    case "palette_search": {
+      const query = String(request.query ?? "").toLowerCase();
+      const results: PaletteSearchResult[] = E2E_GIT_BRANCHES
+        .filter((branch) => branch.name.toLowerCase().includes(query))
+        .map((branch): PaletteSearchResult => {
+          const start = branch.name.toLowerCase().indexOf(query);
+          return {
+            kind: "branch",
+            label: branch.name,
+            detail: branch.subject,
+            value: branch.name,
+            score: query.length,
+            matchedIndices: Array.from({ length: query.length }, (_, offset) => start + offset),
+          };
+        });
+      return results.slice(0, Number(request.limit ?? 50)) as T;
+    }
+
     case "get_runtime_stats": {
       const activePanes = Array.from(state.panes.values()).filter((pane) => !pane.suspended).length;
       const suspendedPanes = Array.from(state.panes.values()).filter((pane) => pane.suspended).length;
@@ -546,12 +721,42 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
       return runtimeStats as T;
     }
 
+    case "set_command_debug_enabled":
+      return undefined as T;
+
+    case "get_command_debug_log":
+      return [] as T;
+
     case "restart_app":
       return undefined as T;
 
+    case "shutdown_all": {
+      const terminatedPanes = state.panes.size;
+      state.panes.clear();
+      const report: ShutdownReport = {
+        drainedJobs: true,
+        persistedJobs: 0,
+        persistedInputPanes: 0,
+        terminatedPanes,
+        failedPanes: [],
+        closedTunnels: 0,
+        durationMs: 0,
+      };
+      return report as T;
+    }
+
     case "set_discord_presence_enabled":
       return undefined as T;
 
+    case "set_osc52_clipboard_enabled":
+      return undefined as T;
+
+    case "set_shell_integration_enabled":
+      return undefined as T;
+
+    case "force_cleanup_processes":
+      return { sessionsScanned: 0, processesKilled: 0, killFailures: 0, sessions: [] } as T;
+
     case "sync_automation_workspaces":
       state.automationWorkspaces = Array.isArray(request.workspaces)
         ? (request.workspaces as AutomationWorkspaceSnapshot[]).map((workspace) => ({ ...workspace }))
@@ -692,14 +897,68 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
         output: `committed in ${String(request.repoRoot ?? MAIN_REPO_ROOT)}: ${String(request.message ?? "")}`,
       } as T;
 
+    case "git_commit_amend":
+      return {
+        output: `amended HEAD in ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
+      } as T;
+
+    case "git_commit_graph":
+      return {
+        refs: ["main"],
+        commits: [],
+        truncated: false,
+      } as T;
+
+    case "git_apply_patch":
+      return {
+        applicable: true,
+        wouldConflict: false,
+        applied: request.checkOnly !== true,
+        files: [],
+        conflicts: [],
+        output: "patch applied",
+      } as T;
+
+    case "git_notes_show":
+    case "git_notes_add":
+    case "git_notes_remove":
+      return {
+        commit: String(request.rev ?? "HEAD"),
+        notesRef: String(request.notesRef ?? "refs/notes/commits"),
+        note: command === "git_notes_add" ? String(request.message ?? "") : null,
+      } as T;
+
+    case "git_clean":
+      return {
+        dryRun: request.dryRun !== false,
+        paths: [],
+      } as T;
+
     case "git_fetch":
       return {
         output: `fetched origin for ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
       } as T;
 
+    case "git_fetch_remote":
+      return {
+        output: `fetched ${String(request.remote ?? "origin")} for ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
+      } as T;
+
+    case "git_create_bundle":
+    case "git_fetch_from_bundle":
+      return {
+        bundlePath: String(request.bundlePath ?? `${E2E_DEFAULT_CWD}/repo.bundle`),
+        heads: [{ commit: "9f3b1f2", refName: "refs/heads/main" }],
+        output: command === "git_create_bundle" ? "bundle created" : "fetched from bundle",
+      } as T;
+
     case "git_pull":
       return {
+        outcome: "upToDate",
+        from: "9f3b1f2",
+        to: "9f3b1f2",
         output: `already up to date for ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
+        conflicts: [],
       } as T;
 
     case "git_push":
@@ -732,6 +991,21 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
         output: `commented on PR #${String(request.number ?? "")}`,
       } as T;
 
+    case "gh_pr_create": {
+      const number = Math.max(...E2E_GITHUB_PRS.map((pr) => pr.number)) + 1;
+      return {
+        number,
+        title: String(request.title ?? ""),
+        state: "OPEN",
+        headRefName: String(request.head ?? "feature/e2e"),
+        baseRefName: String(request.base ?? "main"),
+        isDraft: Boolean(request.draft),
+        updatedAt: "2026-02-20T09:00:00Z",
+        url: `https://example.com/super-vibing/pull/${number}`,
+        author: { login: "nagara" },
+      } as T;
+    }
+
     case "gh_pr_merge_squash":
       return {
         output: `squash-merged PR #${String(request.number ?? "")}${Boolean(request.deleteBranch) ? " and deleted branch" : ""}`,
@@ -813,6 +1087,825 @@ export async function e2eInvoke<T>(command: string, args?: Record<string, unknow
         ],
       } as T;
 
+    case "read_workspace_file": {
+      const content = "# super-vibing e2e fixture\n";
+      return {
+        path: String(request.path ?? ""),
+        content,
+        etag: "e2e-etag-1",
+        modifiedAtMs: Date.parse("2026-02-19T11:00:00Z"),
+        sizeBytes: content.length,
+      } as T;
+    }
+
+    case "write_workspace_file":
+      return {
+        path: String(request.path ?? ""),
+        etag: `e2e-etag-${Date.now()}`,
+        modifiedAtMs: Date.now(),
+        sizeBytes: String(request.content ?? "").length,
+      } as T;
+
+    case "list_scaffold_templates":
+      return [
+        { name: "component", source: "builtin" },
+        { name: "migration", source: "builtin" },
+        { name: "test", source: "builtin" },
+      ] as T;
+
+    case "scaffold_file":
+      return {
+        path: String(request.targetPath ?? ""),
+        template: String(request.template ?? ""),
+        templateSource: "builtin",
+        staged: Boolean(request.stage),
+      } as T;
+
+    case "workspace_find_replace": {
+      const applied = typeof request.confirmToken === "string";
+      return {
+        files: [],
+        totalMatches: 0,
+        skipped: [],
+        previewToken: "e2e-preview-token",
+        applied,
+        staged: false,
+      } as T;
+    }
+
+    case "gh_list_accounts":
+      return {
+        accounts: [
+          { hostname: "github.com", login: "nagara", active: true },
+          { hostname: "github.com", login: "buildbot", active: false },
+        ],
+        selectedLogin: null,
+      } as T;
+
+    case "gh_set_repo_account":
+      return undefined as T;
+
+    case "gh_audit_log":
+      return [] as T;
+
+    case "gh_network_status":
+      return { online: true, offlineSinceMs: null, lastError: null, staleReads: [] } as T;
+
+    case "gh_list_orgs":
+      return [
+        { login: "super-vibing", description: "Terminal workspaces", avatarUrl: null, stale: false },
+      ] as T;
+
+    case "gh_list_repos": {
+      const owner = String(request.owner ?? "nagara");
+      const query = String(request.query ?? "").toLowerCase();
+      const repos = ["super-vibing", "dotfiles"]
+        .filter((name) => name.includes(query))
+        .map((name) => ({
+          fullName: `${owner}/${name}`,
+          name,
+          description: null,
+          private: false,
+          fork: false,
+          archived: false,
+          defaultBranch: "main",
+          cloneUrl: `https://github.com/${owner}/${name}.git`,
+          sshUrl: `git@github.com:${owner}/${name}.git`,
+          htmlUrl: `https://github.com/${owner}/${name}`,
+          updatedAt: "2026-02-19T11:00:00Z",
+        }));
+      return {
+        repos,
+        page: Number(request.page ?? 1),
+        perPage: Number(request.perPage ?? 30),
+        hasNextPage: false,
+        totalCount: repos.length,
+        stale: false,
+      } as T;
+    }
+
+    case "git_stash_list":
+      return [
+        {
+          index: 0,
+          reference: "stash@{0}",
+          branch: "main",
+          message: "On main: e2e work in progress",
+          commit: "4c1d2e3",
+          createdAt: Date.parse("2026-02-19T10:00:00Z") / 1000,
+        },
+      ] as T;
+
+    case "git_stash_push":
+      return {
+        index: 0,
+        reference: "stash@{0}",
+        branch: "main",
+        message: `On main: ${String(request.message ?? "e2e stash")}`,
+        commit: "5d2e3f4",
+        createdAt: Math.floor(Date.now() / 1000),
+      } as T;
+
+    case "git_stash_apply":
+    case "git_stash_pop":
+    case "git_stash_drop":
+      return {
+        output: `${command.replace("git_stash_", "")} stash@{${String(request.index ?? 0)}}`,
+      } as T;
+
+    case "stack_list":
+      return [
+        {
+          branch: "feature/visual-regression",
+          parent: "main",
+          trunk: "main",
+          children: [],
+          depth: 1,
+          position: 1,
+          stackSize: 1,
+          worktreePath: FEATURE_WORKTREE_PATH,
+          needsRestack: false,
+        },
+      ] as T;
+
+    case "stack_set_parent":
+      return {
+        output: request.parent
+          ? `${String(request.branch ?? "")} stacked on ${String(request.parent)}`
+          : `${String(request.branch ?? "")} removed from stack`,
+      } as T;
+
+    case "stack_restack":
+      return { restacked: [], upToDate: [String(request.branch ?? "feature/visual-regression")] } as T;
+
+    case "stack_annotate_prs":
+      return [
+        { branch: String(request.branch ?? "feature/visual-regression"), position: 1, prNumber: 418, updated: true },
+      ] as T;
+
+    case "generate_changelog": {
+      const entries = [
+        {
+          section: "Features",
+          title: "Add visual regression coverage",
+          prNumber: 418,
+          author: "nagara",
+          commit: "9f3b1f2",
+        },
+      ];
+      return {
+        from: String(request.from ?? ""),
+        to: String(request.to ?? "HEAD"),
+        markdown: "## What's Changed\n\n### Features\n\n- Add visual regression coverage (#418) by nagara\n",
+        entries,
+      } as T;
+    }
+
+    case "gh_create_release":
+      return {
+        output: `https://example.com/super-vibing/releases/tag/${String(request.tag ?? "")}`,
+      } as T;
+
+    case "git_log": {
+      const skip = Number(request.skip ?? 0);
+      const limit = Number(request.limit ?? 50);
+      const entries = [
+        {
+          hash: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+          shortHash: "9f3b1f2",
+          parents: ["8e2a0e1b6b3c4d5e7f8091a2b3c4d5e6f708192a"],
+          authorName: "nagara",
+          authorEmail: "nagara@example.com",
+          authoredAt: Date.parse("2026-02-19T11:00:00Z") / 1000,
+          subject: "Add visual regression coverage",
+          refs: ["HEAD -> main", "origin/main"],
+        },
+      ].slice(skip, skip + limit);
+      return { entries, skip, limit, hasMore: false } as T;
+    }
+
+    case "detect_test_command":
+      return { command: "npm test", framework: "vitest" } as T;
+
+    case "run_tests":
+      return {
+        id: crypto.randomUUID(),
+        worktreePath: String(request.worktreePath ?? MAIN_WORKTREE_PATH),
+        command: String(request.command ?? "npm test"),
+        framework: "vitest",
+        paneId: request.paneId ? String(request.paneId) : null,
+        status: "running",
+        exitCode: null,
+        summary: null,
+        startedAtMs: Date.now(),
+        finishedAtMs: null,
+      } as T;
+
+    case "get_test_run":
+      throw new Error(`test run \`${String(request.runId ?? "")}\` does not exist`);
+
+    case "list_test_runs":
+      return [] as T;
+
+    case "git_blame":
+      return {
+        path: String(request.path ?? ""),
+        rev: request.rev ? String(request.rev) : null,
+        lines: [
+          {
+            lineNumber: 1,
+            originalLineNumber: 1,
+            commit: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+            author: "nagara",
+            authorEmail: "nagara@example.com",
+            authorTime: Date.parse("2026-02-19T11:00:00Z") / 1000,
+            summary: "Add visual regression coverage",
+            previousPath: null,
+            isBoundary: false,
+            content: "# super-vibing e2e fixture",
+          },
+        ],
+      } as T;
+
+    case "git_cherry_pick":
+    case "git_cherry_pick_continue":
+      return {
+        completed: true,
+        output: `cherry-picked into ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
+        state: { inProgress: false, currentCommit: null, remaining: 0, conflicts: [] },
+      } as T;
+
+    case "git_cherry_pick_state":
+      return { inProgress: false, currentCommit: null, remaining: 0, conflicts: [] } as T;
+
+    case "git_cherry_pick_abort":
+      return { output: "cherry-pick aborted" } as T;
+
+    case "git_rebase_start":
+    case "git_rebase_continue":
+      return {
+        completed: true,
+        output: `rebased ${String(request.repoRoot ?? MAIN_REPO_ROOT)}${request.onto ? ` onto ${String(request.onto)}` : ""}`,
+        state: {
+          inProgress: false,
+          interactive: false,
+          branch: null,
+          onto: null,
+          currentStep: 0,
+          totalSteps: 0,
+          currentCommit: null,
+          conflicts: [],
+        },
+      } as T;
+
+    case "git_rebase_state":
+      return {
+        inProgress: false,
+        interactive: false,
+        branch: null,
+        onto: null,
+        currentStep: 0,
+        totalSteps: 0,
+        currentCommit: null,
+        conflicts: [],
+      } as T;
+
+    case "git_rebase_abort":
+      return { output: "rebase aborted" } as T;
+
+    case "git_merge":
+      return {
+        completed: true,
+        output: `merged ${String(request.ref ?? "")}${Boolean(request.squash) ? " (squashed)" : ""}`,
+        conflicts: [],
+      } as T;
+
+    case "git_merge_abort":
+      return { output: "merge aborted" } as T;
+
+    case "git_conflict_list":
+      return [] as T;
+
+    case "git_conflict_versions":
+      throw new Error(`\`${String(request.path ?? "")}\` is not conflicted`);
+
+    case "git_conflict_resolve":
+    case "git_conflict_write":
+      return { output: `resolved ${String(request.path ?? "")}` } as T;
+
+    case "git_conflict_mark_resolved":
+      return {
+        output: `marked resolved: ${Array.isArray(request.paths) ? request.paths.join(", ") : ""}`,
+      } as T;
+
+    case "get_notification_policy":
+      return null as T;
+
+    case "set_notification_policy":
+      return undefined as T;
+
+    case "git_lfs_status":
+      return { installed: true, version: "3.5.1", usesLfs: false, files: [] } as T;
+
+    case "git_lfs_pull":
+      return { output: "" } as T;
+
+    case "diff_workspace_env": {
+      const side = (value: unknown) => ({
+        workspaceId: null,
+        worktreePath: String(value ?? MAIN_WORKTREE_PATH),
+        shell: "/bin/bash",
+        toolsProbed: false,
+      });
+      return {
+        a: side(request.workspaceA),
+        b: side(request.workspaceB),
+        differences: [],
+        identicalCount: 0,
+      } as T;
+    }
+
+    case "git_diff_refs":
+      return {
+        base: String(request.base ?? "main"),
+        head: String(request.head ?? "HEAD"),
+        mergeBase: request.mergeBase === false ? null : "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+        files: [],
+        summary: { filesChanged: 0, additions: 0, deletions: 0 },
+        truncated: false,
+      } as T;
+
+    case "list_maintenance_schedules":
+    case "list_maintenance_reports":
+      return [] as T;
+
+    case "set_maintenance_schedule": {
+      const intervals = (request.intervals ?? {}) as Record<string, number>;
+      const now = Date.now();
+      return {
+        repoRoot: String(request.repoRoot ?? MAIN_REPO_ROOT),
+        enabled: request.enabled !== false,
+        intervals,
+        lastRunMs: Object.fromEntries(Object.keys(intervals).map((task) => [task, now])),
+      } as T;
+    }
+
+    case "remove_maintenance_schedule":
+      return false as T;
+
+    case "run_maintenance_now": {
+      const tasks =
+        Array.isArray(request.tasks) && request.tasks.length > 0
+          ? request.tasks.map(String)
+          : ["maintenance", "worktreePrune", "cacheCleanup"];
+      return tasks.map((task) => ({
+        id: crypto.randomUUID(),
+        repoRoot: String(request.repoRoot ?? MAIN_REPO_ROOT),
+        task,
+        trigger: "manual",
+        success: true,
+        output: "",
+        startedAtMs: Date.now(),
+        durationMs: 0,
+      })) as T;
+    }
+
+    case "git_diff_stat":
+      return { files: [], summary: { filesChanged: 0, additions: 0, deletions: 0 } } as T;
+
+    case "check_branch_name":
+      return [] as T;
+
+    case "get_branch_policy":
+      return null as T;
+
+    case "set_branch_policy":
+      return undefined as T;
+
+    case "git_stage_lines":
+    case "git_unstage_lines":
+      return {
+        output: `${command === "git_stage_lines" ? "staged" : "unstaged"} ${String(request.path ?? "")}:${String(request.startLine ?? "")}-${String(request.endLine ?? "")}`,
+      } as T;
+
+    case "git_file_history":
+      return { path: String(request.path ?? ""), entries: [], hasMore: false, patchesTruncated: false } as T;
+
+    case "get_project_config":
+    case "request_repo_trust":
+    case "set_repo_trusted": {
+      const repoRoot = String(request.repoRoot ?? MAIN_REPO_ROOT);
+      return {
+        repoRoot,
+        path: `${repoRoot}/.supervibing.toml`,
+        exists: false,
+        trust: "unknown",
+        readOnly: true,
+        needsPrompt: false,
+        config: {},
+        executableEntries: {},
+        configSha256: null,
+        error: null,
+      } as T;
+    }
+
+    case "git_reflog":
+      return [
+        {
+          selector: "HEAD@{0}",
+          index: 0,
+          hash: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+          shortHash: "9f3b1f2",
+          action: "commit",
+          message: "commit: Add visual regression coverage",
+          subject: "Add visual regression coverage",
+          recordedAt: Date.parse("2026-02-19T11:00:00Z") / 1000,
+        },
+      ].slice(0, Number(request.limit ?? 50)) as T;
+
+    case "git_recover_ref":
+      return {
+        output: `created branch ${String(request.branch ?? "")} at ${String(request.target ?? "")}${Boolean(request.checkout) ? " and checked out" : ""}`,
+      } as T;
+
+    case "git_bisect_start":
+    case "git_bisect_good":
+    case "git_bisect_bad":
+    case "git_bisect_skip":
+    case "git_bisect_status":
+      return {
+        active: false,
+        bad: null,
+        good: [],
+        skipped: [],
+        candidate: null,
+        remainingRevisions: null,
+        remainingSteps: null,
+        firstBad: null,
+        output: null,
+      } as T;
+
+    case "git_bisect_reset":
+      return { output: "bisect reset" } as T;
+
+    case "git_maintenance":
+      return (Array.isArray(request.tasks) ? request.tasks : []).map((task) => ({
+        task,
+        success: true,
+        output: "",
+        durationMs: 0,
+      })) as T;
+
+    case "git_config_get":
+      return { key: String(request.key ?? ""), value: null, scope: null, redacted: false } as T;
+
+    case "git_config_set": {
+      const unset = request.value === undefined || request.value === null;
+      return {
+        key: String(request.key ?? ""),
+        value: unset ? null : String(request.value),
+        scope: unset ? null : String(request.scope ?? "local"),
+        redacted: false,
+      } as T;
+    }
+
+    case "git_config_list":
+      return [
+        { key: "user.name", value: "nagara", scope: "global", redacted: false },
+        { key: "core.bare", value: "false", scope: "local", redacted: false },
+      ] as T;
+
+    case "open_ssh_tunnel":
+      return {
+        id: crypto.randomUUID(),
+        workspaceId: String(request.workspaceId ?? ""),
+        host: String(request.host ?? ""),
+        direction: request.direction === "remote" ? "remote" : "local",
+        localPort: Number(request.localPort ?? 0),
+        remotePort: Number(request.remotePort ?? 0),
+        targetHost: String(request.targetHost ?? "localhost"),
+        status: "starting",
+        pid: 4243,
+        error: null,
+        startedAtMs: Date.now(),
+      } as T;
+
+    case "close_ssh_tunnel":
+      return undefined as T;
+
+    case "list_ssh_tunnels":
+      return [] as T;
+
+    case "git_signing_config":
+      return {
+        format: "openpgp",
+        signingKey: null,
+        signByDefault: false,
+        program: "gpg",
+        programAvailable: true,
+        keyAvailable: false,
+      } as T;
+
+    case "list_pane_triggers":
+      return [] as T;
+
+    case "set_pane_trigger":
+      return {
+        id: String(request.id ?? crypto.randomUUID()),
+        name: String(request.name ?? ""),
+        pattern: String(request.pattern ?? ""),
+        caseInsensitive: Boolean(request.caseInsensitive),
+        paneId: request.paneId ? String(request.paneId) : null,
+        workspaceId: request.workspaceId ? String(request.workspaceId) : null,
+        notify: request.notify !== false,
+        webhookUrl: request.webhookUrl ? String(request.webhookUrl) : null,
+        once: Boolean(request.once),
+        cooldownMs: Number(request.cooldownMs ?? 2000),
+        enabled: request.enabled !== false,
+        fireCount: 0,
+      } as T;
+
+    case "remove_pane_trigger":
+      return undefined as T;
+
+    case "git_list_hooks": {
+      const repoRoot = String(request.repoRoot ?? MAIN_REPO_ROOT);
+      return {
+        hooksDir: `${repoRoot}/.git/hooks`,
+        hooksPath: null,
+        hooks: [],
+        managers: [],
+        policy: "allow",
+      } as T;
+    }
+
+    case "set_git_hook_policy":
+      return String(request.policy ?? "allow") as T;
+
+    case "docker_project_info":
+      return {
+        worktreePath: String(request.worktreePath ?? MAIN_WORKTREE_PATH),
+        project: "repo",
+        dockerfiles: ["Dockerfile"],
+        composeFiles: [],
+        dockerAvailable: true,
+        images: [],
+        containers: [],
+        error: null,
+      } as T;
+
+    case "docker_build_image": {
+      const tag = Boolean(request.compose) ? null : String(request.tag ?? "repo:latest");
+      return {
+        paneId: String(request.paneId ?? ""),
+        command: tag
+          ? `docker build -f ${String(request.dockerfile ?? "Dockerfile")} -t ${tag} .`
+          : "docker compose -f docker-compose.yml build",
+        tag,
+      } as T;
+    }
+
+    case "clone_repository": {
+      const destination = normalizePath(String(request.destination ?? `${E2E_DEFAULT_CWD}/clone`));
+      const options = (request.options ?? {}) as { branch?: string };
+      return {
+        isGitRepo: true,
+        repoRoot: destination,
+        worktreePath: destination,
+        branch: options.branch ?? "main",
+      } as T;
+    }
+
+    case "git_fetch_unshallow":
+      return {
+        output: `fetched ${String(request.remote ?? "origin")} for ${String(request.repoRoot ?? MAIN_REPO_ROOT)}`,
+      } as T;
+
+    case "set_worktree_sync_strategy":
+      return (request.strategy === "rebase" ? "rebase" : "merge") as T;
+
+    case "sync_worktree_with_default":
+      return syncWorktreeResult(String(request.worktreePath ?? FEATURE_WORKTREE_PATH), request.strategy) as T;
+
+    case "sync_worktrees_with_default": {
+      const paths = Array.isArray(request.worktreePaths)
+        ? request.worktreePaths.map(String)
+        : Array.from(state.worktrees.values())
+            .filter((entry) => !entry.isMainWorktree)
+            .map((entry) => entry.worktreePath);
+      return paths.map((path) => syncWorktreeResult(path, request.strategy)) as T;
+    }
+
+    case "list_workspace_candidates":
+      return [] as T;
+
+    case "dismiss_workspace_candidate":
+      return undefined as T;
+
+    case "create_scratch_worktree": {
+      const name = String(request.name ?? "").trim() || `e2e${state.worktreeCounter}`;
+      const branch = `scratch/${name}`;
+      const worktreePath = `${MAIN_REPO_ROOT}/worktrees/${safeBranchSegment(branch)}`;
+      const createdAtMs = Date.now();
+      const record: ScratchWorktree = {
+        repoRoot: MAIN_REPO_ROOT,
+        worktreePath,
+        branch,
+        baseCommit: "9f3b1f2",
+        createdAtMs,
+        expiresAtMs: createdAtMs + Number(request.ttlMinutes ?? 60 * 24) * 60_000,
+        autoRemove: request.autoRemove === true,
+        offeredAtMs: null,
+      };
+      state.worktrees.set(normalizePath(worktreePath), {
+        id: `wt-${state.worktreeCounter++}`,
+        repoRoot: MAIN_REPO_ROOT,
+        branch,
+        worktreePath,
+        head: record.baseCommit,
+        isMainWorktree: false,
+        isDetached: false,
+        isLocked: false,
+        isPrunable: false,
+        isDirty: false,
+      });
+      state.branchByPath.set(normalizePath(worktreePath), branch);
+      state.scratchWorktrees.set(normalizePath(worktreePath), record);
+      return record as T;
+    }
+
+    case "list_scratch_worktrees": {
+      const now = Date.now();
+      return Array.from(state.scratchWorktrees.values()).map((record) => ({
+        ...record,
+        expired: record.expiresAtMs <= now,
+        dirty: false,
+        hasNewCommits: false,
+      })) as T;
+    }
+
+    case "extend_scratch_worktree":
+    case "keep_scratch_worktree": {
+      const worktreePath = normalizePath(String(request.worktreePath ?? ""));
+      const record = state.scratchWorktrees.get(worktreePath);
+      if (!record) {
+        throw new Error("scratch worktree not found");
+      }
+      if (command === "keep_scratch_worktree") {
+        state.scratchWorktrees.delete(worktreePath);
+        return record as T;
+      }
+      record.expiresAtMs = Date.now() + Number(request.ttlMinutes ?? 0) * 60_000;
+      record.offeredAtMs = null;
+      return record as T;
+    }
+
+    case "init_repository": {
+      const path = normalizePath(String(request.path ?? `${E2E_DEFAULT_CWD}/new-repo`));
+      return {
+        isGitRepo: true,
+        repoRoot: path,
+        worktreePath: path,
+        branch: String(request.initialBranch ?? "main"),
+      } as T;
+    }
+
+    case "list_gitignore_templates":
+      return ["node", "rust", "python", "go", "java", "minimal"] as T;
+
+    case "gitignore_add_patterns": {
+      const patterns = Array.isArray(request.patterns) ? request.patterns.map(String) : [];
+      return {
+        path: `${String(request.repoRoot ?? MAIN_REPO_ROOT)}/.gitignore`,
+        added: patterns.filter((pattern) => pattern !== "node_modules/"),
+        existing: patterns.filter((pattern) => pattern === "node_modules/"),
+      } as T;
+    }
+
+    case "git_check_ignored": {
+      const paths = Array.isArray(request.paths) ? request.paths.map(String) : [];
+      return paths.map((path) => {
+        const ignored = path.startsWith("node_modules/");
+        return {
+          path,
+          ignored,
+          source: ignored ? ".gitignore" : null,
+          line: ignored ? 1 : null,
+          pattern: ignored ? "node_modules/" : null,
+        };
+      }) as T;
+    }
+
+    case "git_reset": {
+      const mode = String(request.mode ?? "mixed");
+      return {
+        mode,
+        from: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+        to: "8e2a0e1b6b3c4d5e7f8091a2b3c4d5e6f708192a",
+        discardedPaths: [],
+        output: mode === "hard" ? "HEAD is now at 8e2a0e1 Initial commit" : "reset completed",
+      } as T;
+    }
+
+    case "git_show_commit": {
+      const authoredAt = Date.parse("2026-02-19T11:00:00Z") / 1000;
+      return {
+        hash: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+        shortHash: "9f3b1f2",
+        parents: ["8e2a0e1b6b3c4d5e7f8091a2b3c4d5e6f708192a"],
+        authorName: "nagara",
+        authorEmail: "nagara@example.com",
+        authoredAt,
+        subject: "Add visual regression coverage",
+        refs: ["HEAD -> main", "origin/main"],
+        signature: null,
+        committerName: "nagara",
+        committerEmail: "nagara@example.com",
+        committedAt: authoredAt,
+        message: "Add visual regression coverage\n",
+        files: [],
+        summary: { filesChanged: 0, additions: 0, deletions: 0 },
+        truncated: false,
+      } as T;
+    }
+
+    case "git_archive":
+      return {
+        outputPath: String(request.outputPath ?? `${E2E_DEFAULT_CWD}/repo.zip`),
+        format: String(request.format ?? "zip"),
+        commit: "9f3b1f2a7c4d5e6f8091a2b3c4d5e6f708192a3b",
+        sizeBytes: 4096,
+      } as T;
+
+    case "move_worktree": {
+      const source = normalizePath(String(request.worktreePath ?? ""));
+      const entry = state.worktrees.get(source);
+      if (!entry) {
+        throw new Error("worktree not found");
+      }
+      if (entry.isMainWorktree) {
+        throw new Error("the main worktree cannot be moved");
+      }
+      const destination = normalizePath(String(request.destination ?? ""));
+      const moved: WorktreeEntry = { ...entry, worktreePath: destination };
+      state.worktrees.delete(source);
+      state.worktrees.set(destination, moved);
+      state.branchByPath.delete(source);
+      state.branchByPath.set(destination, moved.branch);
+      return moved as T;
+    }
+
+    case "repair_worktrees":
+      return {
+        repaired: [],
+        errors: [],
+        worktrees: Array.from(state.worktrees.values()),
+      } as T;
+
+    case "git_status_all_worktrees":
+      return Object.fromEntries(
+        Array.from(state.worktrees.values()).map((entry) => [
+          entry.worktreePath,
+          { status: { ...defaultGitStatus(entry.worktreePath), branch: entry.branch }, error: null },
+        ]),
+      ) as T;
+
+    case "git_diff_stream": {
+      const path = String(request.path ?? "");
+      const text = [
+        `diff --git a/${path} b/${path}`,
+        `--- a/${path}`,
+        `+++ b/${path}`,
+        "@@ -1,1 +1,2 @@",
+        " import { defineConfig } from \"@playwright/test\";",
+        "+// e2e mock streamed patch",
+        "",
+      ].join("\n");
+      const onEvent = args?.onEvent as ((event: GitDiffStreamEvent) => void) | undefined;
+      onEvent?.({ kind: "chunk", index: 0, text, totalBytes: text.length });
+      onEvent?.({ kind: "done", index: 1, text: "", totalBytes: text.length });
+      return {
+        path,
+        staged: Boolean(request.staged),
+        untracked: false,
+        chunks: 1,
+        totalBytes: text.length,
+      } as T;
+    }
+
+    case "git_set_upstream": {
+      const branch = String(request.branch ?? "main");
+      const upstream = String(request.remoteBranch ?? `origin/${branch}`);
+      return {
+        output: `branch '${branch}' set up to track '${upstream}'.`,
+      } as T;
+    }
+
+    case "git_unset_upstream":
+      return {
+        output: `upstream unset for ${String(request.branch ?? "main")}`,
+      } as T;
+
     default:
       throw new Error(`unsupported e2e tauri command '${command}'`);
   }
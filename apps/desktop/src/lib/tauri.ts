@@ -1,6 +1,6 @@
 import { Channel, invoke } from "@tauri-apps/api/core";
 import { open as openDialog } from "@tauri-apps/plugin-dialog";
-import { e2eInvoke, e2ePickDirectory, e2eSpawnPane, isE2eRuntime } from "./tauri-e2e";
+import { e2eInvoke, e2ePickDirectory, e2eSpawnPane, e2eSpawnPanes, isE2eRuntime } from "./tauri-e2e";
 import type {
   AutomationReportRequest,
   AutomationWorkspaceSnapshot,
@@ -11,46 +11,216 @@ import type {
   KanbanStateSnapshot,
   KanbanTaskRun,
   SyncKanbanStateRequest,
+  BranchNamePolicy,
+  BranchPolicyRequest,
+  BranchPolicyViolation,
+  CheckBranchNameRequest,
+  CloneRepositoryRequest,
+  CommandInvocation,
+  CreateScratchWorktreeRequest,
   CreateWorktreeRequest,
+  DetectTestCommandRequest,
+  DiffWorkspaceEnvRequest,
+  DirectoryProbe,
+  DockerBuildRequest,
+  DockerBuildResponse,
+  DockerProjectInfo,
+  ForceCleanupProcessesRequest,
+  ForceCleanupProcessesResponse,
+  GenerateChangelogRequest,
+  GenerateChangelogResponse,
+  GitApplyPatchRequest,
+  GitApplyPatchResponse,
+  GitArchiveRequest,
+  GitArchiveResponse,
+  GitBisectMarkRequest,
+  GitBisectStartRequest,
+  GitBisectStatus,
+  GitBlameRequest,
+  GitBlameResponse,
   GitBranchInfo,
+  GitBundleResponse,
   GitCheckoutBranchRequest,
+  GitCherryPickRequest,
+  GitCherryPickResponse,
+  GitCherryPickState,
+  GitCleanRequest,
+  GitCleanResponse,
+  GitCloneProgress,
   GitCommandResponse,
+  GitCommitAmendRequest,
+  GitCommitDetail,
+  GitCommitGraph,
+  GitCommitGraphRequest,
   GitCommitRequest,
+  GitConfigEntry,
+  GitConfigGetRequest,
+  GitConfigListRequest,
+  GitConfigSetRequest,
+  GitConflictFile,
+  GitConflictMarkResolvedRequest,
+  GitConflictPathRequest,
+  GitConflictResolveRequest,
+  GitConflictVersions,
+  GitConflictWriteRequest,
   GitCreateBranchRequest,
+  GitCreateBundleRequest,
   GitDeleteBranchRequest,
+  GitDiffRefsRequest,
+  GitDiffRefsResponse,
   GitDiffRequest,
   GitDiffResponse,
+  GitDiffStatRequest,
+  GitDiffStatResponse,
+  GitDiffStreamEvent,
+  GitDiffStreamRequest,
+  GitDiffStreamSummary,
   GitDiscardPathsRequest,
+  GitFetchFromBundleRequest,
+  GitFetchRemoteRequest,
+  GitFetchUnshallowRequest,
+  GitFileHistory,
+  GitFileHistoryRequest,
+  GitHookPolicy,
+  GitHooksInfo,
+  GitHubAccountsResponse,
+  GitHubAuditEntry,
   GitHubIssueCommentRequest,
   GitHubIssueEditAssigneesRequest,
   GitHubIssueEditLabelsRequest,
   GitHubIssueRequest,
   GitHubIssueSummary,
   GitHubListRequest,
+  GitHubNetworkStatus,
+  GitHubOrgsRequest,
+  GitHubOrgSummary,
   GitHubPrCommentRequest,
+  GitHubPrCreateRequest,
   GitHubPrMergeRequest,
   GitHubPrRequest,
   GitHubPrSummary,
+  GitHubReleaseCreateRequest,
+  GitHubRepoPage,
+  GitHubReposRequest,
   GitHubRunRequest,
   GitHubRunSummary,
+  GitHubSetRepoAccountRequest,
   GitHubWorkflowSummary,
+  GitIgnoreMatch,
+  GitignoreAddPatternsRequest,
+  GitignoreAddPatternsResponse,
+  GitLfsPullRequest,
+  GitLfsStatus,
+  GitLineRangeRequest,
+  GitLogPage,
+  GitLogRequest,
+  GitMaintenanceRequest,
+  GitMaintenanceTaskResult,
+  GitMergeRequest,
+  GitMergeResponse,
+  GitNoteResponse,
+  GitNotesAddRequest,
+  GitNotesRequest,
   GitPathsRequest,
+  GitPullRequest,
+  GitPullResponse,
+  GitPushRequest,
+  GitRebaseResponse,
+  GitRebaseStartRequest,
+  GitRebaseState,
+  GitRecoverRefRequest,
+  GitReflogEntry,
+  GitReflogRequest,
   GitRepoRequest,
+  GitResetRequest,
+  GitResetResponse,
+  GitSetUpstreamRequest,
+  GitShowCommitRequest,
+  GitSigningConfig,
+  GitStashEntry,
+  GitStashPushRequest,
+  GitStashRequest,
+  GitStatusRequest,
   GitStatusSnapshot,
+  GitUnsetUpstreamRequest,
+  GitWorktreeStatus,
   GlobalCommandRequest,
+  ImportExternalWorktreeRequest,
+  InitRepositoryRequest,
+  ListMaintenanceReportsRequest,
+  ListSshTunnelsRequest,
+  ListTestRunsRequest,
+  MaintenanceReport,
+  MaintenanceSchedule,
+  MoveWorktreeRequest,
+  NotificationPolicy,
+  OpenSshTunnelRequest,
+  PaletteSearchRequest,
+  PaletteSearchResult,
+  PaneChangeSet,
   PaneCommandResult,
+  PaneEnvironment,
   PaneEvent,
+  PaneSizeResponse,
+  PaneTrigger,
+  ProjectConfig,
   PruneWorktreesRequest,
   PruneWorktreesResponse,
+  ReadWorkspaceFileRequest,
   RemoveWorktreeRequest,
   RemoveWorktreeResponse,
+  ReplayPaneOutputResponse,
   ResizePaneRequest,
+  RepairWorktreesRequest,
+  RepairWorktreesResponse,
   RepoContext,
+  RestoreSnapshotRequest,
+  RestoreSnapshotResponse,
+  RunMaintenanceRequest,
+  RunTestsRequest,
   RuntimeStats,
+  ScaffoldFileRequest,
+  ScaffoldFileResponse,
+  ScaffoldTemplate,
+  ScratchWorktree,
+  ScratchWorktreeStatus,
+  SetBranchPolicyRequest,
+  SetGitHookPolicyRequest,
+  SetMaintenanceScheduleRequest,
+  SetPaneTriggerRequest,
+  SetRepoTrustedRequest,
+  ShutdownAllRequest,
+  ShutdownReport,
+  SnapshotWorktreeRequest,
   SpawnPaneRequest,
   SpawnPaneResponse,
+  SpawnPaneResult,
+  SpawnPanesRequest,
+  SshTunnel,
+  StackBranchEntry,
+  StackBranchRequest,
+  StackPrAnnotation,
+  StackRestackResponse,
+  StackSetParentRequest,
+  SyncWorktreeRequest,
+  SyncWorktreesRequest,
+  TestCommandDetection,
+  TestRun,
+  TestRunDetail,
+  TestRunRequest,
+  UnsentInput,
+  WorkspaceEnvDiff,
+  WorkspaceFileContent,
+  WorkspaceFileWriteResponse,
+  WorkspaceFindReplaceRequest,
+  WorkspaceFindReplaceResponse,
   WorktreeEntry,
+  WorkspaceCandidate,
+  WorktreeSnapshot,
+  WorktreeSyncResult,
+  WorktreeSyncStrategy,
   WritePaneInputRequest,
+  WriteWorkspaceFileRequest,
 } from "../types";
 
 type PaneEventListener = (event: PaneEvent) => void;
@@ -111,6 +281,36 @@ export async function spawnPane(request: SpawnPaneRequest): Promise<SpawnPaneRes
   return invokeCommand<SpawnPaneResponse>("spawn_pane", { request, output });
 }
 
+export async function spawnPanes(request: SpawnPanesRequest): Promise<SpawnPaneResult[]> {
+  if (E2E_RUNTIME) {
+    return e2eSpawnPanes(request, emitPaneEvent);
+  }
+
+  const output = new Channel<PaneEvent>((event) => {
+    emitPaneEvent(event);
+  });
+
+  return invokeCommand<SpawnPaneResult[]>("spawn_panes", { request, output });
+}
+
+/** Panes spawned without their own `output` channel stream through this shared one. */
+export async function attachOutputChannel(): Promise<void> {
+  if (E2E_RUNTIME) {
+    await invokeCommand("attach_output_channel");
+    return;
+  }
+
+  const output = new Channel<PaneEvent>((event) => {
+    emitPaneEvent(event);
+  });
+
+  await invokeCommand("attach_output_channel", { output });
+}
+
+export async function detachOutputChannel(): Promise<void> {
+  await invokeCommand("detach_output_channel");
+}
+
 export async function writePaneInput(request: WritePaneInputRequest): Promise<void> {
   await invokeCommand("write_pane_input", { request });
 }
@@ -119,6 +319,33 @@ export async function resizePane(request: ResizePaneRequest): Promise<void> {
   await invokeCommand("resize_pane", { request });
 }
 
+export async function getPaneSize(paneId: string): Promise<PaneSizeResponse> {
+  return invokeCommand<PaneSizeResponse>("get_pane_size", { request: { paneId } });
+}
+
+/** Reads the environment of the pane's shell, or of `pid` when it belongs to the pane's session. */
+export async function getPaneEnvironment(paneId: string, pid?: number): Promise<PaneEnvironment> {
+  return invokeCommand<PaneEnvironment>("get_pane_environment", { request: { paneId, pid } });
+}
+
+/** Pass `dismiss` to clear the recovered input once the user has restored or discarded it. */
+export async function getUnsentInput(paneId: string, dismiss?: boolean): Promise<UnsentInput | null> {
+  return invokeCommand<UnsentInput | null>("get_unsent_input", { request: { paneId, dismiss } });
+}
+
+/** Re-emits a pane's retained output to its listeners, e.g. after the terminal view remounts. */
+export async function replayPaneOutput(paneId: string, maxBytes?: number): Promise<ReplayPaneOutputResponse> {
+  if (E2E_RUNTIME) {
+    return invokeCommand<ReplayPaneOutputResponse>("replay_pane_output", { request: { paneId, maxBytes } });
+  }
+
+  const output = new Channel<PaneEvent>((event) => {
+    emitPaneEvent(event);
+  });
+
+  return invokeCommand<ReplayPaneOutputResponse>("replay_pane_output", { request: { paneId, maxBytes }, output });
+}
+
 export async function closePane(paneId: string): Promise<void> {
   await invokeCommand("close_pane", { request: { paneId } });
 }
@@ -131,12 +358,34 @@ export async function resumePane(paneId: string): Promise<void> {
   await invokeCommand("resume_pane", { request: { paneId } });
 }
 
+export async function startPaneChangeTracking(paneId: string, worktreePath: string): Promise<void> {
+  await invokeCommand("start_pane_change_tracking", { request: { paneId, worktreePath } });
+}
+
+export async function stopPaneChangeTracking(paneId: string): Promise<void> {
+  await invokeCommand("stop_pane_change_tracking", { request: { paneId } });
+}
+
+export async function getPaneChangeSet(paneId: string, includeIgnored?: boolean): Promise<PaneChangeSet> {
+  return invokeCommand<PaneChangeSet>("get_pane_change_set", { request: { paneId, includeIgnored } });
+}
+
 export async function resolveRepoContext(cwd: string): Promise<RepoContext> {
   return invokeCommand<RepoContext>("resolve_repo_context", { request: { cwd } });
 }
 
-export async function createWorktree(request: CreateWorktreeRequest): Promise<WorktreeEntry> {
-  return invokeCommand<WorktreeEntry>("create_worktree", { request });
+export async function probeDirectory(path: string): Promise<DirectoryProbe> {
+  return invokeCommand<DirectoryProbe>("probe_directory", { request: { path } });
+}
+
+export async function createWorktree(
+  request: CreateWorktreeRequest,
+  onProgress?: (progress: GitCloneProgress) => void,
+): Promise<WorktreeEntry> {
+  if (E2E_RUNTIME || !onProgress) {
+    return invokeCommand<WorktreeEntry>("create_worktree", { request });
+  }
+  return invokeCommand<WorktreeEntry>("create_worktree", { request, onProgress: new Channel(onProgress) });
 }
 
 export async function listWorktrees(repoRoot: string): Promise<WorktreeEntry[]> {
@@ -147,28 +396,145 @@ export async function removeWorktree(request: RemoveWorktreeRequest): Promise<Re
   return invokeCommand<RemoveWorktreeResponse>("remove_worktree", { request });
 }
 
+export async function importExternalWorktree(request: ImportExternalWorktreeRequest): Promise<WorktreeEntry> {
+  return invokeCommand<WorktreeEntry>("import_external_worktree", { request });
+}
+
+export async function setWorktreeSyncStrategy(
+  repoRoot: string,
+  strategy: WorktreeSyncStrategy,
+): Promise<WorktreeSyncStrategy> {
+  return invokeCommand<WorktreeSyncStrategy>("set_worktree_sync_strategy", { request: { repoRoot, strategy } });
+}
+
+export async function syncWorktreeWithDefault(request: SyncWorktreeRequest): Promise<WorktreeSyncResult> {
+  return invokeCommand<WorktreeSyncResult>("sync_worktree_with_default", { request });
+}
+
+export async function syncWorktreesWithDefault(request: SyncWorktreesRequest): Promise<WorktreeSyncResult[]> {
+  return invokeCommand<WorktreeSyncResult[]>("sync_worktrees_with_default", { request });
+}
+
+export async function createScratchWorktree(request: CreateScratchWorktreeRequest): Promise<ScratchWorktree> {
+  return invokeCommand<ScratchWorktree>("create_scratch_worktree", { request });
+}
+
+export async function listScratchWorktrees(repoRoot?: string): Promise<ScratchWorktreeStatus[]> {
+  return invokeCommand<ScratchWorktreeStatus[]>("list_scratch_worktrees", { request: { repoRoot } });
+}
+
+export async function extendScratchWorktree(worktreePath: string, ttlMinutes: number): Promise<ScratchWorktree> {
+  return invokeCommand<ScratchWorktree>("extend_scratch_worktree", { request: { worktreePath, ttlMinutes } });
+}
+
+export async function keepScratchWorktree(worktreePath: string): Promise<ScratchWorktree> {
+  return invokeCommand<ScratchWorktree>("keep_scratch_worktree", { request: { worktreePath } });
+}
+
+export async function moveWorktree(request: MoveWorktreeRequest): Promise<WorktreeEntry> {
+  return invokeCommand<WorktreeEntry>("move_worktree", { request });
+}
+
+export async function repairWorktrees(request: RepairWorktreesRequest): Promise<RepairWorktreesResponse> {
+  return invokeCommand<RepairWorktreesResponse>("repair_worktrees", { request });
+}
+
 export async function pruneWorktrees(request: PruneWorktreesRequest): Promise<PruneWorktreesResponse> {
   return invokeCommand<PruneWorktreesResponse>("prune_worktrees", { request });
 }
 
+export async function listSnapshots(repoRoot: string): Promise<WorktreeSnapshot[]> {
+  return invokeCommand<WorktreeSnapshot[]>("list_snapshots", { request: { repoRoot } });
+}
+
+export async function snapshotWorktree(request: SnapshotWorktreeRequest): Promise<WorktreeSnapshot> {
+  return invokeCommand<WorktreeSnapshot>("snapshot_worktree", { request });
+}
+
+export async function restoreSnapshot(request: RestoreSnapshotRequest): Promise<RestoreSnapshotResponse> {
+  return invokeCommand<RestoreSnapshotResponse>("restore_snapshot", { request });
+}
+
 export async function runGlobalCommand(
   request: GlobalCommandRequest,
 ): Promise<PaneCommandResult[]> {
   return invokeCommand<PaneCommandResult[]>("run_global_command", { request });
 }
 
+export async function paletteSearch(request: PaletteSearchRequest): Promise<PaletteSearchResult[]> {
+  return invokeCommand<PaletteSearchResult[]>("palette_search", { request });
+}
+
 export async function getRuntimeStats(): Promise<RuntimeStats> {
   return invokeCommand<RuntimeStats>("get_runtime_stats");
 }
 
+export async function setCommandDebugEnabled(enabled: boolean): Promise<void> {
+  await invokeCommand("set_command_debug_enabled", { request: { enabled } });
+}
+
+export async function getCommandDebugLog(): Promise<CommandInvocation[]> {
+  return invokeCommand<CommandInvocation[]>("get_command_debug_log");
+}
+
 export async function restartApp(): Promise<void> {
   await invokeCommand("restart_app");
 }
 
+/** Quit flow: stops automation, terminates panes, and exits the app unless `exit` is false. */
+export async function shutdownAll(request: ShutdownAllRequest = {}): Promise<ShutdownReport> {
+  return invokeCommand<ShutdownReport>("shutdown_all", { request });
+}
+
+/** Kills processes that outlived their closed panes' sessions. */
+export async function forceCleanupProcesses(
+  request: ForceCleanupProcessesRequest = {},
+): Promise<ForceCleanupProcessesResponse> {
+  return invokeCommand<ForceCleanupProcessesResponse>("force_cleanup_processes", { request });
+}
+
+/** Dockerfiles, compose files, and the project's local images/containers for a worktree. */
+export async function dockerProjectInfo(worktreePath: string): Promise<DockerProjectInfo> {
+  return invokeCommand<DockerProjectInfo>("docker_project_info", { request: { worktreePath } });
+}
+
+export async function dockerBuildImage(request: DockerBuildRequest): Promise<DockerBuildResponse> {
+  return invokeCommand<DockerBuildResponse>("docker_build_image", { request });
+}
+
+export async function readWorkspaceFile(request: ReadWorkspaceFileRequest): Promise<WorkspaceFileContent> {
+  return invokeCommand<WorkspaceFileContent>("read_workspace_file", { request });
+}
+
+export async function writeWorkspaceFile(request: WriteWorkspaceFileRequest): Promise<WorkspaceFileWriteResponse> {
+  return invokeCommand<WorkspaceFileWriteResponse>("write_workspace_file", { request });
+}
+
+export async function listScaffoldTemplates(worktreePath: string): Promise<ScaffoldTemplate[]> {
+  return invokeCommand<ScaffoldTemplate[]>("list_scaffold_templates", { request: { worktreePath } });
+}
+
+export async function scaffoldFile(request: ScaffoldFileRequest): Promise<ScaffoldFileResponse> {
+  return invokeCommand<ScaffoldFileResponse>("scaffold_file", { request });
+}
+
+export async function workspaceFindReplace(request: WorkspaceFindReplaceRequest): Promise<WorkspaceFindReplaceResponse> {
+  return invokeCommand<WorkspaceFindReplaceResponse>("workspace_find_replace", { request });
+}
+
 export async function setDiscordPresenceEnabled(enabled: boolean): Promise<void> {
   await invokeCommand("set_discord_presence_enabled", { request: { enabled } });
 }
 
+export async function setOsc52ClipboardEnabled(enabled: boolean): Promise<void> {
+  await invokeCommand("set_osc52_clipboard_enabled", { request: { enabled } });
+}
+
+/** Default for panes spawned without an explicit `shellIntegration` flag. */
+export async function setShellIntegrationEnabled(enabled: boolean): Promise<void> {
+  await invokeCommand("set_shell_integration_enabled", { request: { enabled } });
+}
+
 export async function pickDirectory(defaultPath?: string): Promise<string | null> {
   if (E2E_RUNTIME) {
     return e2ePickDirectory(defaultPath);
@@ -214,7 +580,11 @@ export async function getKanbanState(): Promise<KanbanStateSnapshot> {
   return invokeCommand<KanbanStateSnapshot>("kanban_state_snapshot");
 }
 
-export async function gitStatus(request: GitRepoRequest): Promise<GitStatusSnapshot> {
+export async function gitStatusAllWorktrees(request: GitRepoRequest): Promise<Record<string, GitWorktreeStatus>> {
+  return invokeCommand<Record<string, GitWorktreeStatus>>("git_status_all_worktrees", { request });
+}
+
+export async function gitStatus(request: GitStatusRequest): Promise<GitStatusSnapshot> {
   return invokeCommand<GitStatusSnapshot>("git_status", { request });
 }
 
@@ -222,14 +592,50 @@ export async function gitDiff(request: GitDiffRequest): Promise<GitDiffResponse>
   return invokeCommand<GitDiffResponse>("git_diff", { request });
 }
 
+export async function gitDiffStream(
+  request: GitDiffStreamRequest,
+  onEvent: (event: GitDiffStreamEvent) => void,
+): Promise<GitDiffStreamSummary> {
+  if (E2E_RUNTIME) {
+    return invokeCommand<GitDiffStreamSummary>("git_diff_stream", { request, onEvent });
+  }
+  return invokeCommand<GitDiffStreamSummary>("git_diff_stream", { request, onEvent: new Channel(onEvent) });
+}
+
 export async function gitStagePaths(request: GitPathsRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_stage_paths", { request });
 }
 
+export async function gitignoreAddPatterns(
+  request: GitignoreAddPatternsRequest,
+): Promise<GitignoreAddPatternsResponse> {
+  return invokeCommand<GitignoreAddPatternsResponse>("gitignore_add_patterns", { request });
+}
+
+export async function gitCheckIgnored(request: GitPathsRequest): Promise<GitIgnoreMatch[]> {
+  return invokeCommand<GitIgnoreMatch[]>("git_check_ignored", { request });
+}
+
 export async function gitUnstagePaths(request: GitPathsRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_unstage_paths", { request });
 }
 
+export async function gitStageLines(request: GitLineRangeRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_stage_lines", { request });
+}
+
+export async function gitUnstageLines(request: GitLineRangeRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_unstage_lines", { request });
+}
+
+export async function gitApplyPatch(request: GitApplyPatchRequest): Promise<GitApplyPatchResponse> {
+  return invokeCommand<GitApplyPatchResponse>("git_apply_patch", { request });
+}
+
+export async function gitClean(request: GitCleanRequest): Promise<GitCleanResponse> {
+  return invokeCommand<GitCleanResponse>("git_clean", { request });
+}
+
 export async function gitDiscardPaths(request: GitDiscardPathsRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_discard_paths", { request });
 }
@@ -238,15 +644,95 @@ export async function gitCommit(request: GitCommitRequest): Promise<GitCommandRe
   return invokeCommand<GitCommandResponse>("git_commit", { request });
 }
 
+export async function gitCommitAmend(request: GitCommitAmendRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_commit_amend", { request });
+}
+
+export async function gitReset(request: GitResetRequest): Promise<GitResetResponse> {
+  return invokeCommand<GitResetResponse>("git_reset", { request });
+}
+
+export async function gitCommitGraph(request: GitCommitGraphRequest): Promise<GitCommitGraph> {
+  return invokeCommand<GitCommitGraph>("git_commit_graph", { request });
+}
+
+export async function gitNotesShow(request: GitNotesRequest): Promise<GitNoteResponse> {
+  return invokeCommand<GitNoteResponse>("git_notes_show", { request });
+}
+
+export async function gitNotesAdd(request: GitNotesAddRequest): Promise<GitNoteResponse> {
+  return invokeCommand<GitNoteResponse>("git_notes_add", { request });
+}
+
+export async function gitNotesRemove(request: GitNotesRequest): Promise<GitNoteResponse> {
+  return invokeCommand<GitNoteResponse>("git_notes_remove", { request });
+}
+
+export async function gitShowCommit(request: GitShowCommitRequest): Promise<GitCommitDetail> {
+  return invokeCommand<GitCommitDetail>("git_show_commit", { request });
+}
+
+export async function gitArchive(request: GitArchiveRequest): Promise<GitArchiveResponse> {
+  return invokeCommand<GitArchiveResponse>("git_archive", { request });
+}
+
 export async function gitFetch(request: GitRepoRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_fetch", { request });
 }
 
-export async function gitPull(request: GitRepoRequest): Promise<GitCommandResponse> {
-  return invokeCommand<GitCommandResponse>("git_pull", { request });
+export async function gitFetchRemote(
+  request: GitFetchRemoteRequest,
+  onProgress?: (progress: GitCloneProgress) => void,
+): Promise<GitCommandResponse> {
+  if (E2E_RUNTIME || !onProgress) {
+    return invokeCommand<GitCommandResponse>("git_fetch_remote", { request });
+  }
+  return invokeCommand<GitCommandResponse>("git_fetch_remote", { request, onProgress: new Channel(onProgress) });
+}
+
+export async function gitFetchUnshallow(request: GitFetchUnshallowRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_fetch_unshallow", { request });
+}
+
+export async function gitCreateBundle(request: GitCreateBundleRequest): Promise<GitBundleResponse> {
+  return invokeCommand<GitBundleResponse>("git_create_bundle", { request });
 }
 
-export async function gitPush(request: GitRepoRequest): Promise<GitCommandResponse> {
+export async function gitFetchFromBundle(request: GitFetchFromBundleRequest): Promise<GitBundleResponse> {
+  return invokeCommand<GitBundleResponse>("git_fetch_from_bundle", { request });
+}
+
+export async function cloneRepository(
+  request: CloneRepositoryRequest,
+  onProgress?: (progress: GitCloneProgress) => void,
+): Promise<RepoContext> {
+  if (E2E_RUNTIME || !onProgress) {
+    return invokeCommand<RepoContext>("clone_repository", { request });
+  }
+  return invokeCommand<RepoContext>("clone_repository", { request, onProgress: new Channel(onProgress) });
+}
+
+export async function initRepository(request: InitRepositoryRequest): Promise<RepoContext> {
+  return invokeCommand<RepoContext>("init_repository", { request });
+}
+
+export async function listGitignoreTemplates(): Promise<string[]> {
+  return invokeCommand<string[]>("list_gitignore_templates");
+}
+
+export async function listWorkspaceCandidates(): Promise<WorkspaceCandidate[]> {
+  return invokeCommand<WorkspaceCandidate[]>("list_workspace_candidates");
+}
+
+export async function dismissWorkspaceCandidate(repoRoot: string): Promise<void> {
+  await invokeCommand("dismiss_workspace_candidate", { request: { repoRoot } });
+}
+
+export async function gitPull(request: GitPullRequest): Promise<GitPullResponse> {
+  return invokeCommand<GitPullResponse>("git_pull", { request });
+}
+
+export async function gitPush(request: GitPushRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("git_push", { request });
 }
 
@@ -266,6 +752,215 @@ export async function gitDeleteBranch(request: GitDeleteBranchRequest): Promise<
   return invokeCommand<GitCommandResponse>("git_delete_branch", { request });
 }
 
+export async function gitSetUpstream(request: GitSetUpstreamRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_set_upstream", { request });
+}
+
+export async function gitUnsetUpstream(request: GitUnsetUpstreamRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_unset_upstream", { request });
+}
+
+export async function gitStashList(request: GitRepoRequest): Promise<GitStashEntry[]> {
+  return invokeCommand<GitStashEntry[]>("git_stash_list", { request });
+}
+
+export async function gitStashPush(request: GitStashPushRequest): Promise<GitStashEntry> {
+  return invokeCommand<GitStashEntry>("git_stash_push", { request });
+}
+
+export async function gitStashApply(request: GitStashRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_stash_apply", { request });
+}
+
+export async function gitStashPop(request: GitStashRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_stash_pop", { request });
+}
+
+export async function gitStashDrop(request: GitStashRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_stash_drop", { request });
+}
+
+export async function stackList(request: GitRepoRequest): Promise<StackBranchEntry[]> {
+  return invokeCommand<StackBranchEntry[]>("stack_list", { request });
+}
+
+export async function stackSetParent(request: StackSetParentRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("stack_set_parent", { request });
+}
+
+export async function stackRestack(request: StackBranchRequest): Promise<StackRestackResponse> {
+  return invokeCommand<StackRestackResponse>("stack_restack", { request });
+}
+
+export async function stackAnnotatePrs(request: StackBranchRequest): Promise<StackPrAnnotation[]> {
+  return invokeCommand<StackPrAnnotation[]>("stack_annotate_prs", { request });
+}
+
+export async function generateChangelog(request: GenerateChangelogRequest): Promise<GenerateChangelogResponse> {
+  return invokeCommand<GenerateChangelogResponse>("generate_changelog", { request });
+}
+
+export async function gitLog(request: GitLogRequest): Promise<GitLogPage> {
+  return invokeCommand<GitLogPage>("git_log", { request });
+}
+
+export async function gitBlame(request: GitBlameRequest): Promise<GitBlameResponse> {
+  return invokeCommand<GitBlameResponse>("git_blame", { request });
+}
+
+export async function gitCherryPick(request: GitCherryPickRequest): Promise<GitCherryPickResponse> {
+  return invokeCommand<GitCherryPickResponse>("git_cherry_pick", { request });
+}
+
+export async function gitCherryPickState(request: GitRepoRequest): Promise<GitCherryPickState> {
+  return invokeCommand<GitCherryPickState>("git_cherry_pick_state", { request });
+}
+
+export async function gitCherryPickContinue(request: GitRepoRequest): Promise<GitCherryPickResponse> {
+  return invokeCommand<GitCherryPickResponse>("git_cherry_pick_continue", { request });
+}
+
+export async function gitCherryPickAbort(request: GitRepoRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_cherry_pick_abort", { request });
+}
+
+export async function gitRebaseStart(request: GitRebaseStartRequest): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_start", { request });
+}
+
+export async function gitRebaseState(request: GitRepoRequest): Promise<GitRebaseState> {
+  return invokeCommand<GitRebaseState>("git_rebase_state", { request });
+}
+
+export async function gitRebaseContinue(request: GitRepoRequest): Promise<GitRebaseResponse> {
+  return invokeCommand<GitRebaseResponse>("git_rebase_continue", { request });
+}
+
+export async function gitRebaseAbort(request: GitRepoRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_rebase_abort", { request });
+}
+
+export async function gitMerge(request: GitMergeRequest): Promise<GitMergeResponse> {
+  return invokeCommand<GitMergeResponse>("git_merge", { request });
+}
+
+export async function gitMergeAbort(request: GitRepoRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_merge_abort", { request });
+}
+
+export async function gitConflictList(request: GitRepoRequest): Promise<GitConflictFile[]> {
+  return invokeCommand<GitConflictFile[]>("git_conflict_list", { request });
+}
+
+export async function gitConflictVersions(request: GitConflictPathRequest): Promise<GitConflictVersions> {
+  return invokeCommand<GitConflictVersions>("git_conflict_versions", { request });
+}
+
+export async function gitConflictResolve(request: GitConflictResolveRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_conflict_resolve", { request });
+}
+
+export async function gitConflictWrite(request: GitConflictWriteRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_conflict_write", { request });
+}
+
+export async function gitConflictMarkResolved(request: GitConflictMarkResolvedRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_conflict_mark_resolved", { request });
+}
+
+export async function gitLfsStatus(request: GitRepoRequest): Promise<GitLfsStatus> {
+  return invokeCommand<GitLfsStatus>("git_lfs_status", { request });
+}
+
+export async function gitLfsPull(request: GitLfsPullRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_lfs_pull", { request });
+}
+
+export async function gitDiffRefs(request: GitDiffRefsRequest): Promise<GitDiffRefsResponse> {
+  return invokeCommand<GitDiffRefsResponse>("git_diff_refs", { request });
+}
+
+export async function gitDiffStat(request: GitDiffStatRequest): Promise<GitDiffStatResponse> {
+  return invokeCommand<GitDiffStatResponse>("git_diff_stat", { request });
+}
+
+/** An empty result means the name satisfies the repo's branch policy. */
+export async function checkBranchName(request: CheckBranchNameRequest): Promise<BranchPolicyViolation[]> {
+  return invokeCommand<BranchPolicyViolation[]>("check_branch_name", { request });
+}
+
+export async function getBranchPolicy(request: BranchPolicyRequest = {}): Promise<BranchNamePolicy | null> {
+  return invokeCommand<BranchNamePolicy | null>("get_branch_policy", { request });
+}
+
+export async function setBranchPolicy(request: SetBranchPolicyRequest): Promise<void> {
+  await invokeCommand("set_branch_policy", { request });
+}
+
+export async function gitFileHistory(request: GitFileHistoryRequest): Promise<GitFileHistory> {
+  return invokeCommand<GitFileHistory>("git_file_history", { request });
+}
+
+export async function gitReflog(request: GitReflogRequest): Promise<GitReflogEntry[]> {
+  return invokeCommand<GitReflogEntry[]>("git_reflog", { request });
+}
+
+export async function gitRecoverRef(request: GitRecoverRefRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_recover_ref", { request });
+}
+
+export async function gitBisectStart(request: GitBisectStartRequest): Promise<GitBisectStatus> {
+  return invokeCommand<GitBisectStatus>("git_bisect_start", { request });
+}
+
+export async function gitBisectGood(request: GitBisectMarkRequest): Promise<GitBisectStatus> {
+  return invokeCommand<GitBisectStatus>("git_bisect_good", { request });
+}
+
+export async function gitBisectBad(request: GitBisectMarkRequest): Promise<GitBisectStatus> {
+  return invokeCommand<GitBisectStatus>("git_bisect_bad", { request });
+}
+
+export async function gitBisectSkip(request: GitBisectMarkRequest): Promise<GitBisectStatus> {
+  return invokeCommand<GitBisectStatus>("git_bisect_skip", { request });
+}
+
+export async function gitBisectStatus(request: GitRepoRequest): Promise<GitBisectStatus> {
+  return invokeCommand<GitBisectStatus>("git_bisect_status", { request });
+}
+
+export async function gitBisectReset(request: GitRepoRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("git_bisect_reset", { request });
+}
+
+export async function gitMaintenance(request: GitMaintenanceRequest): Promise<GitMaintenanceTaskResult[]> {
+  return invokeCommand<GitMaintenanceTaskResult[]>("git_maintenance", { request });
+}
+
+export async function gitConfigGet(request: GitConfigGetRequest): Promise<GitConfigEntry> {
+  return invokeCommand<GitConfigEntry>("git_config_get", { request });
+}
+
+export async function gitConfigSet(request: GitConfigSetRequest): Promise<GitConfigEntry> {
+  return invokeCommand<GitConfigEntry>("git_config_set", { request });
+}
+
+export async function gitConfigList(request: GitConfigListRequest): Promise<GitConfigEntry[]> {
+  return invokeCommand<GitConfigEntry[]>("git_config_list", { request });
+}
+
+export async function gitSigningConfig(request: GitRepoRequest): Promise<GitSigningConfig> {
+  return invokeCommand<GitSigningConfig>("git_signing_config", { request });
+}
+
+export async function gitListHooks(request: GitRepoRequest): Promise<GitHooksInfo> {
+  return invokeCommand<GitHooksInfo>("git_list_hooks", { request });
+}
+
+export async function setGitHookPolicy(request: SetGitHookPolicyRequest): Promise<GitHookPolicy> {
+  return invokeCommand<GitHookPolicy>("set_git_hook_policy", { request });
+}
+
 export async function ghListPrs(request: GitHubListRequest): Promise<GitHubPrSummary[]> {
   return invokeCommand<GitHubPrSummary[]>("gh_list_prs", { request });
 }
@@ -282,6 +977,10 @@ export async function ghPrComment(request: GitHubPrCommentRequest): Promise<GitC
   return invokeCommand<GitCommandResponse>("gh_pr_comment", { request });
 }
 
+export async function ghPrCreate(request: GitHubPrCreateRequest): Promise<GitHubPrSummary> {
+  return invokeCommand<GitHubPrSummary>("gh_pr_create", { request });
+}
+
 export async function ghPrMergeSquash(request: GitHubPrMergeRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("gh_pr_merge_squash", { request });
 }
@@ -329,3 +1028,120 @@ export async function ghRunRerunFailed(request: GitHubRunRequest): Promise<GitCo
 export async function ghRunCancel(request: GitHubRunRequest): Promise<GitCommandResponse> {
   return invokeCommand<GitCommandResponse>("gh_run_cancel", { request });
 }
+
+export async function ghListAccounts(request: GitRepoRequest): Promise<GitHubAccountsResponse> {
+  return invokeCommand<GitHubAccountsResponse>("gh_list_accounts", { request });
+}
+
+export async function ghSetRepoAccount(request: GitHubSetRepoAccountRequest): Promise<void> {
+  await invokeCommand("gh_set_repo_account", { request });
+}
+
+export async function ghAuditLog(): Promise<GitHubAuditEntry[]> {
+  return invokeCommand<GitHubAuditEntry[]>("gh_audit_log");
+}
+
+export async function ghNetworkStatus(): Promise<GitHubNetworkStatus> {
+  return invokeCommand<GitHubNetworkStatus>("gh_network_status");
+}
+
+export async function ghListOrgs(request: GitHubOrgsRequest): Promise<GitHubOrgSummary[]> {
+  return invokeCommand<GitHubOrgSummary[]>("gh_list_orgs", { request });
+}
+
+export async function ghListRepos(request: GitHubReposRequest): Promise<GitHubRepoPage> {
+  return invokeCommand<GitHubRepoPage>("gh_list_repos", { request });
+}
+
+export async function ghCreateRelease(request: GitHubReleaseCreateRequest): Promise<GitCommandResponse> {
+  return invokeCommand<GitCommandResponse>("gh_create_release", { request });
+}
+
+export async function detectTestCommand(request: DetectTestCommandRequest): Promise<TestCommandDetection> {
+  return invokeCommand<TestCommandDetection>("detect_test_command", { request });
+}
+
+export async function runTests(request: RunTestsRequest): Promise<TestRun> {
+  return invokeCommand<TestRun>("run_tests", { request });
+}
+
+export async function getTestRun(request: TestRunRequest): Promise<TestRunDetail> {
+  return invokeCommand<TestRunDetail>("get_test_run", { request });
+}
+
+export async function listTestRuns(request: ListTestRunsRequest = {}): Promise<TestRun[]> {
+  return invokeCommand<TestRun[]>("list_test_runs", { request });
+}
+
+export async function getNotificationPolicy(): Promise<NotificationPolicy | null> {
+  return invokeCommand<NotificationPolicy | null>("get_notification_policy");
+}
+
+export async function setNotificationPolicy(policy: NotificationPolicy): Promise<void> {
+  await invokeCommand("set_notification_policy", { request: { policy } });
+}
+
+/** Compares env files, tool versions and git config between two workspaces; secrets are redacted. */
+export async function diffWorkspaceEnv(request: DiffWorkspaceEnvRequest): Promise<WorkspaceEnvDiff> {
+  return invokeCommand<WorkspaceEnvDiff>("diff_workspace_env", { request });
+}
+
+export async function listMaintenanceSchedules(): Promise<MaintenanceSchedule[]> {
+  return invokeCommand<MaintenanceSchedule[]>("list_maintenance_schedules");
+}
+
+export async function setMaintenanceSchedule(request: SetMaintenanceScheduleRequest): Promise<MaintenanceSchedule> {
+  return invokeCommand<MaintenanceSchedule>("set_maintenance_schedule", { request });
+}
+
+/** Resolves to `false` when the repo had no schedule. */
+export async function removeMaintenanceSchedule(repoRoot: string): Promise<boolean> {
+  return invokeCommand<boolean>("remove_maintenance_schedule", { request: { repoRoot } });
+}
+
+export async function listMaintenanceReports(
+  request: ListMaintenanceReportsRequest = {},
+): Promise<MaintenanceReport[]> {
+  return invokeCommand<MaintenanceReport[]>("list_maintenance_reports", { request });
+}
+
+export async function runMaintenanceNow(request: RunMaintenanceRequest): Promise<MaintenanceReport[]> {
+  return invokeCommand<MaintenanceReport[]>("run_maintenance_now", { request });
+}
+
+export async function getProjectConfig(request: GitRepoRequest): Promise<ProjectConfig> {
+  return invokeCommand<ProjectConfig>("get_project_config", { request });
+}
+
+/** Shows the native trust dialog when the repo's config needs a decision. */
+export async function requestRepoTrust(request: GitRepoRequest): Promise<ProjectConfig> {
+  return invokeCommand<ProjectConfig>("request_repo_trust", { request });
+}
+
+export async function setRepoTrusted(request: SetRepoTrustedRequest): Promise<ProjectConfig> {
+  return invokeCommand<ProjectConfig>("set_repo_trusted", { request });
+}
+
+export async function openSshTunnel(request: OpenSshTunnelRequest): Promise<SshTunnel> {
+  return invokeCommand<SshTunnel>("open_ssh_tunnel", { request });
+}
+
+export async function closeSshTunnel(tunnelId: string): Promise<void> {
+  await invokeCommand("close_ssh_tunnel", { request: { tunnelId } });
+}
+
+export async function listSshTunnels(request: ListSshTunnelsRequest = {}): Promise<SshTunnel[]> {
+  return invokeCommand<SshTunnel[]>("list_ssh_tunnels", { request });
+}
+
+export async function listPaneTriggers(): Promise<PaneTrigger[]> {
+  return invokeCommand<PaneTrigger[]>("list_pane_triggers");
+}
+
+export async function setPaneTrigger(request: SetPaneTriggerRequest): Promise<PaneTrigger> {
+  return invokeCommand<PaneTrigger>("set_pane_trigger", { request });
+}
+
+export async function removePaneTrigger(triggerId: string): Promise<void> {
+  await invokeCommand("remove_pane_trigger", { request: { triggerId } });
+}
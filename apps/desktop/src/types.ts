@@ -182,12 +182,36 @@ export interface SpawnPaneRequest {
   cols?: number;
   initCommand?: string;
   executeInit?: boolean;
+  /** Overrides the global shell integration setting for this spawn. */
+  shellIntegration?: boolean;
 }
 
 export interface SpawnPaneResponse {
   paneId: string;
   cwd: string;
   shell: string;
+  /** Unsubmitted input left over from the pane's previous session. */
+  recoveredInput?: string;
+}
+
+/** Spawns `paneIds`, or `count` panes with generated ids; all panes share one output channel. */
+export interface SpawnPanesRequest {
+  paneIds?: string[];
+  count?: number;
+  cwd?: string;
+  shell?: string;
+  rows?: number;
+  cols?: number;
+  initCommand?: string;
+  executeInit?: boolean;
+  shellIntegration?: boolean;
+}
+
+export interface SpawnPaneResult {
+  paneId: string;
+  cwd: string;
+  shell: string;
+  error?: string | null;
 }
 
 export interface WritePaneInputRequest {
@@ -202,12 +226,85 @@ export interface ResizePaneRequest {
   cols: number;
 }
 
+/** Last requested size; `ptyRows`/`ptyCols` are what the PTY itself reports. */
+export interface PaneSizeResponse {
+  paneId: string;
+  rows: number;
+  cols: number;
+  resizedAtMs?: number | null;
+  ptyRows?: number | null;
+  ptyCols?: number | null;
+}
+
+export interface PaneEnvironment {
+  paneId: string;
+  pid: number;
+  command?: string | null;
+  variables: Record<string, string>;
+}
+
+/** Input typed into a pane but not yet submitted with Enter. */
+export interface UnsentInput {
+  paneId: string;
+  pending: string;
+  /** Input restored from the pane's previous session. */
+  recovered?: string | null;
+  failedWrite: boolean;
+  updatedAtMs: number;
+}
+
+export interface ReplayPaneOutputResponse {
+  paneId: string;
+  replayedBytes: number;
+  /** Output older than the retained history was dropped before the replay. */
+  historyTruncated: boolean;
+}
+
+export interface PaneFileChange {
+  path: string;
+  kind: "created" | "modified" | "removed";
+  changedAtMs: number;
+}
+
+/** Files a pane's session touched in its worktree since tracking started. */
+export interface PaneChangeSet {
+  paneId: string;
+  worktreePath: string;
+  tracking: boolean;
+  startedAtMs: number;
+  stoppedAtMs?: number | null;
+  files: PaneFileChange[];
+  truncated: boolean;
+}
+
 export interface PaneEvent {
   paneId: string;
-  kind: "output" | "exit" | "error";
+  kind: "output" | "exit" | "error" | "clipboard" | "replay" | "link_detected" | "diagnostic" | "links";
   payload: string;
 }
 
+/** Element of the JSON array payload of a `links` pane event. */
+export interface OutputLink {
+  kind: "file" | "url";
+  text: string;
+  target: string;
+  line?: number | null;
+  column?: number | null;
+  worktreePath?: string | null;
+  relativePath?: string | null;
+}
+
+/** JSON payload of a `diagnostic` pane event. */
+export interface PaneDiagnostic {
+  source: "cargo" | "tsc" | "eslint" | "test";
+  severity: "error" | "warning";
+  file: string;
+  line: number;
+  column?: number | null;
+  code?: string | null;
+  message: string;
+}
+
 export interface WorkspaceTab {
   id: string;
   repoRoot: string;
@@ -222,7 +319,27 @@ export interface RepoContext {
   branch: string;
 }
 
-export type WorktreeCreateMode = "newBranch" | "existingBranch";
+export interface GitRemote {
+  name: string;
+  url: string;
+}
+
+/** Read-only facts about a directory, gathered before opening it as a workspace. */
+export interface DirectoryProbe {
+  path: string;
+  isGitRepo: boolean;
+  repoRoot?: string | null;
+  isLinkedWorktree: boolean;
+  currentBranch?: string | null;
+  defaultBranch?: string | null;
+  remotes: GitRemote[];
+  projectTypes: string[];
+  sizeEstimateBytes: number;
+  sizeEstimateTruncated: boolean;
+}
+
+/** `remoteBranch` takes `<remote>/<branch>`, fetches it, and creates a local tracking branch. */
+export type WorktreeCreateMode = "newBranch" | "existingBranch" | "remoteBranch";
 
 export interface WorktreeEntry {
   id: string;
@@ -241,10 +358,14 @@ export interface WorktreeEntry {
 
 export interface GitStatusFile {
   path: string;
+  oldPath?: string | null;
   code: string;
   staged: boolean;
   unstaged: boolean;
   untracked: boolean;
+  /** Lines changed against HEAD (staged + unstaged); null for untracked, binary, and conflicted paths. */
+  additions?: number | null;
+  deletions?: number | null;
 }
 
 export interface GitStatusSnapshot {
@@ -256,23 +377,99 @@ export interface GitStatusSnapshot {
   stagedCount: number;
   unstagedCount: number;
   untrackedCount: number;
+  /** Unmerged paths; they also count towards staged/unstaged. */
+  conflictedCount: number;
+  operation: GitOperation | null;
   files: GitStatusFile[];
 }
 
+export type GitOperation = "rebase" | "applyMailbox" | "merge" | "cherryPick" | "revert" | "bisect";
+
+/** Payload of the `git_status_changed` event emitted by the backend worktree watchers. */
+export interface GitStatusChangedEvent {
+  worktreePath: string;
+  changed: GitStatusFile[];
+  removed: string[];
+  status: GitStatusSnapshot;
+}
+
+export interface GitWorktreeStatus {
+  status?: GitStatusSnapshot | null;
+  error?: string | null;
+}
+
+export type DiffBackend = "git" | "difftastic" | "delta";
+
+export interface StructuredDiffSpan {
+  start: number;
+  end: number;
+  content: string;
+  highlight: string;
+}
+
+export interface StructuredDiffSide {
+  lineNumber: number;
+  changes: StructuredDiffSpan[];
+}
+
+export interface StructuredDiff {
+  language: string | null;
+  status: string;
+  hunks: Array<{ lines: Array<{ old: StructuredDiffSide | null; new: StructuredDiffSide | null }> }>;
+}
+
+/** Token ranges on a modified line, as UTF-16 offsets into the old/new line text. */
+export interface IntralineChange {
+  oldLine: number;
+  newLine: number;
+  removed: Array<{ start: number; end: number }>;
+  added: Array<{ start: number; end: number }>;
+}
+
 export interface GitDiffResponse {
   path: string;
   staged: boolean;
   patch: string;
+  /** Not in the index: `patch` is the whole file as an addition (empty above the size cap). */
+  untracked?: boolean;
+  isBinary?: boolean;
+  oldSize?: number | null;
+  newSize?: number | null;
+  intraline?: IntralineChange[] | null;
+  backend?: DiffBackend;
+  structured?: StructuredDiff | null;
+  rendered?: string | null;
+  backendError?: string | null;
 }
 
 export interface GitBranchInfo {
   name: string;
   isCurrent: boolean;
   upstream: string | null;
+  ahead: number;
+  behind: number;
+  upstreamGone: boolean;
   commit: string;
   subject: string;
 }
 
+export interface CommandInvocation {
+  argv: string[];
+  exitCode: number | null;
+  startedAtMs: number;
+  durationMs: number;
+}
+
+/**
+ * Emitted as `command:debug` after each git/gh command, or background run (`command` is then the
+ * worker label, e.g. `git_status_watch`), while command debugging is enabled.
+ */
+export interface CommandDebugInfo {
+  command: string;
+  durationMs: number;
+  invocations: CommandInvocation[];
+}
+
 export interface GitCommandResponse {
   output: string;
 }
@@ -296,6 +493,7 @@ export interface GitHubPrSummary {
   updatedAt: string;
   url: string;
   author?: GitHubUser | null;
+  stale?: boolean;
 }
 
 export interface GitHubIssueSummary {
@@ -307,6 +505,7 @@ export interface GitHubIssueSummary {
   author?: GitHubUser | null;
   labels: GitHubLabel[];
   assignees: GitHubUser[];
+  stale?: boolean;
 }
 
 export interface GitHubWorkflowSummary {
@@ -314,6 +513,7 @@ export interface GitHubWorkflowSummary {
   name: string;
   state: string;
   path: string;
+  stale?: boolean;
 }
 
 export interface GitHubRunSummary {
@@ -329,6 +529,7 @@ export interface GitHubRunSummary {
   createdAt: string;
   updatedAt: string;
   url: string;
+  stale?: boolean;
 }
 
 export interface CreateWorktreeRequest {
@@ -338,18 +539,94 @@ export interface CreateWorktreeRequest {
   baseRef?: string;
 }
 
+/** Adopts a worktree created outside the app, as long as its repository is already open. */
+export interface ImportExternalWorktreeRequest {
+  path: string;
+  /** Workspace name; defaults to the worktree directory name. */
+  name?: string;
+}
+
 export interface RemoveWorktreeRequest {
   repoRoot: string;
   worktreePath: string;
   force: boolean;
   deleteBranch: boolean;
+  /** Saves uncommitted changes first when force-removing a dirty worktree. */
+  backup?: WorktreeBackupMode;
 }
 
+/** `stash` adds an entry to `git stash list` (untracked files included); `branch` creates a `backup/` branch. */
+export type WorktreeBackupMode = "stash" | "branch";
+
 export interface RemoveWorktreeResponse {
   worktreePath: string;
   branch: string;
   branchDeleted: boolean;
   warning?: string;
+  /** The `backup/` branch; stash backups are found by `backupCommit` in the stash list. */
+  backupRef?: string | null;
+  backupCommit?: string | null;
+}
+
+export type WorktreeSyncStrategy = "merge" | "rebase";
+
+export interface SyncWorktreeRequest {
+  repoRoot: string;
+  worktreePath: string;
+  strategy?: WorktreeSyncStrategy;
+}
+
+export interface SyncWorktreesRequest {
+  repoRoot: string;
+  worktreePaths?: string[];
+  strategy?: WorktreeSyncStrategy;
+}
+
+export interface CreateScratchWorktreeRequest {
+  repoRoot: string;
+  name?: string;
+  baseRef?: string;
+  ttlMinutes?: number;
+  autoRemove?: boolean;
+}
+
+export interface ScratchWorktree {
+  repoRoot: string;
+  worktreePath: string;
+  branch: string;
+  baseCommit: string;
+  createdAtMs: number;
+  expiresAtMs: number;
+  autoRemove: boolean;
+  offeredAtMs?: number | null;
+}
+
+export interface ScratchWorktreeStatus extends ScratchWorktree {
+  expired: boolean;
+  dirty: boolean;
+  hasNewCommits: boolean;
+}
+
+export interface WorktreeSyncConflict {
+  path: string;
+  kind: string;
+  hasBase: boolean;
+  hasOurs: boolean;
+  hasTheirs: boolean;
+}
+
+export interface WorktreeSyncResult {
+  worktreePath: string;
+  branch?: string | null;
+  base?: string | null;
+  strategy: WorktreeSyncStrategy;
+  status: "upToDate" | "updated" | "conflicted" | "failed";
+  incomingCommits: number;
+  headBefore?: string | null;
+  headAfter?: string | null;
+  conflicts: WorktreeSyncConflict[];
+  output: string;
+  error?: string | null;
 }
 
 export interface PruneWorktreesRequest {
@@ -363,6 +640,38 @@ export interface PruneWorktreesResponse {
   output: string;
 }
 
+export interface WorktreeSnapshot {
+  name: string;
+  refName: string;
+  commit: string;
+  sourceWorktreePath?: string | null;
+  createdAt: string;
+}
+
+export interface SnapshotWorktreeRequest {
+  worktreePath: string;
+  name: string;
+  /**
+   * Leaves the changes in the worktree after recording them. Otherwise the worktree is cleared
+   * with `reset --hard` and `clean -fd`, which deletes untracked files; the snapshot ref is then
+   * their only copy. Ignored files are left alone.
+   */
+  keepChanges?: boolean;
+}
+
+export interface RestoreSnapshotRequest {
+  worktreePath: string;
+  name: string;
+  dropAfterRestore?: boolean;
+}
+
+export interface RestoreSnapshotResponse {
+  snapshot: WorktreeSnapshot;
+  worktreePath: string;
+  dropped: boolean;
+  output: string;
+}
+
 export interface GlobalCommandRequest {
   paneIds: string[];
   command: string;
@@ -375,125 +684,1283 @@ export interface PaneCommandResult {
   error?: string;
 }
 
+export type PaletteItemKind = "command" | "branch" | "worktree" | "file" | "issue" | "pr";
+
+export interface PaletteSearchRequest {
+  query: string;
+  repoRoot?: string;
+  kinds?: PaletteItemKind[];
+  limit?: number;
+  /** Reloads the repo's cached branches, worktrees, files, issues, and PRs first. */
+  refresh?: boolean;
+}
+
+export interface PaletteSearchResult {
+  kind: PaletteItemKind;
+  label: string;
+  detail?: string | null;
+  value: string;
+  score: number;
+  /** Character positions in `label` that matched the query, for highlighting. */
+  matchedIndices: number[];
+}
+
 export interface RuntimeStats {
   activePanes: number;
   suspendedPanes: number;
 }
 
-export interface GitRepoRequest {
+export interface ShutdownAllRequest {
+  drainTimeoutMs?: number;
+  gracePeriodMs?: number;
+  exit?: boolean;
+}
+
+export interface ShutdownReport {
+  drainedJobs: boolean;
+  persistedJobs: number;
+  persistedInputPanes: number;
+  terminatedPanes: number;
+  failedPanes: string[];
+  closedTunnels: number;
+  durationMs: number;
+}
+
+export interface ForceCleanupProcessesRequest {
+  /** Limit the sweep to one closed pane's session. */
+  paneId?: string;
+}
+
+export interface ClosedSessionCleanup {
+  paneId: string;
+  processGroupId: number;
+  killed: number;
+}
+
+export interface ForceCleanupProcessesResponse {
+  sessionsScanned: number;
+  processesKilled: number;
+  killFailures: number;
+  sessions: ClosedSessionCleanup[];
+}
+
+export interface DockerImage {
+  id: string;
+  repository: string;
+  tag: string;
+  size: string;
+  createdSince: string;
+}
+
+export interface DockerContainer {
+  id: string;
+  name: string;
+  image: string;
+  state: string;
+  status: string;
+  ports: string;
+}
+
+export interface DockerProjectInfo {
+  worktreePath: string;
+  project: string;
+  dockerfiles: string[];
+  composeFiles: string[];
+  dockerAvailable: boolean;
+  images: DockerImage[];
+  containers: DockerContainer[];
+  error: string | null;
+}
+
+export interface DockerBuildRequest {
+  worktreePath: string;
+  paneId: string;
+  compose?: boolean;
+  dockerfile?: string;
+  tag?: string;
+}
+
+export interface DockerBuildResponse {
+  paneId: string;
+  command: string;
+  tag: string | null;
+}
+
+export interface ReadWorkspaceFileRequest {
   repoRoot: string;
+  path: string;
 }
 
-export interface GitDiffRequest extends GitRepoRequest {
+export interface WorkspaceFileContent {
   path: string;
-  staged: boolean;
+  content: string;
+  /** Pass back as `expectedEtag` so a write fails if the file changed in between. */
+  etag: string;
+  modifiedAtMs: number;
+  sizeBytes: number;
 }
 
-export interface GitPathsRequest extends GitRepoRequest {
-  paths: string[];
+export interface WriteWorkspaceFileRequest {
+  repoRoot: string;
+  path: string;
+  content: string;
+  expectedEtag?: string;
+  force?: boolean;
 }
 
-export interface GitDiscardPathsRequest extends GitPathsRequest {
-  force: boolean;
+export interface WorkspaceFileWriteResponse {
+  path: string;
+  etag: string;
+  modifiedAtMs: number;
+  sizeBytes: number;
 }
 
-export interface GitCommitRequest extends GitRepoRequest {
-  message: string;
+export interface ScaffoldTemplate {
+  name: string;
+  /** `workspace` templates come from `.supervibing/templates/*.tmpl` and shadow builtins of the same name. */
+  source: "workspace" | "builtin";
 }
 
-export interface GitCheckoutBranchRequest extends GitRepoRequest {
-  branch: string;
+export interface ScaffoldFileRequest {
+  worktreePath: string;
+  template: string;
+  targetPath: string;
+  variables?: Record<string, string>;
+  stage?: boolean;
 }
 
-export interface GitCreateBranchRequest extends GitRepoRequest {
-  branch: string;
-  baseRef?: string;
-  checkout?: boolean;
+export interface ScaffoldFileResponse {
+  path: string;
+  template: string;
+  templateSource: ScaffoldTemplate["source"];
+  staged: boolean;
 }
 
-export interface GitDeleteBranchRequest extends GitRepoRequest {
-  branch: string;
-  force?: boolean;
+/** Previews a regex replacement across tracked files; resend with `confirmToken` to apply it. */
+export interface WorkspaceFindReplaceRequest {
+  worktreePath: string;
+  pattern: string;
+  /** Supports `$1`-style capture references. */
+  replacement: string;
+  caseInsensitive?: boolean;
+  paths?: string[];
+  /** The `previewToken` of the preview being confirmed. */
+  confirmToken?: string;
+  stage?: boolean;
 }
 
-export interface GitHubListRequest extends GitRepoRequest {
-  limit?: number;
+export interface WorkspaceReplaceFile {
+  path: string;
+  matches: number;
+  patch: string;
 }
 
-export interface GitHubPrRequest extends GitRepoRequest {
-  number: number;
+export interface WorkspaceFindReplaceResponse {
+  files: WorkspaceReplaceFile[];
+  totalMatches: number;
+  /** Binary or oversized files that were not searched. */
+  skipped: string[];
+  previewToken: string;
+  applied: boolean;
+  staged: boolean;
 }
 
-export interface GitHubPrCommentRequest extends GitHubPrRequest {
-  body: string;
+export interface GitRepoRequest {
+  repoRoot: string;
 }
 
-export interface GitHubPrMergeRequest extends GitHubPrRequest {
-  deleteBranch?: boolean;
+export interface GitStatusRequest extends GitRepoRequest {
+  renameThreshold?: number;
 }
 
-export interface GitHubIssueRequest extends GitRepoRequest {
-  number: number;
+export interface GitDiffRequest extends GitRepoRequest {
+  path: string;
+  staged: boolean;
+  backend?: DiffBackend;
+  intraline?: boolean;
 }
 
-export interface GitHubIssueCommentRequest extends GitHubIssueRequest {
-  body: string;
+export interface GitDiffStreamRequest extends GitRepoRequest {
+  path: string;
+  staged: boolean;
+  chunkBytes?: number;
 }
 
-export interface GitHubIssueEditLabelsRequest extends GitHubIssueRequest {
-  addLabels: string[];
-  removeLabels: string[];
+export interface GitDiffStreamEvent {
+  kind: "chunk" | "done";
+  index: number;
+  text: string;
+  totalBytes: number;
 }
 
-export interface GitHubIssueEditAssigneesRequest extends GitHubIssueRequest {
-  addAssignees: string[];
-  removeAssignees: string[];
+export interface GitDiffStreamSummary {
+  path: string;
+  staged: boolean;
+  /** The path is untracked and was streamed as a new-file patch, as `git_diff` returns it. */
+  untracked: boolean;
+  chunks: number;
+  totalBytes: number;
 }
 
-export interface GitHubRunRequest extends GitRepoRequest {
-  runId: number;
+export interface GitPathsRequest extends GitRepoRequest {
+  paths: string[];
 }
 
-export interface AutomationWorkspaceSnapshot {
-  workspaceId: string;
-  name: string;
-  repoRoot: string;
-  worktreePath: string;
-  runtimePaneIds: string[];
+export interface GitignoreAddPatternsRequest extends GitRepoRequest {
+  patterns: string[];
 }
 
-export interface AutomationReportRequest {
-  jobId: string;
-  ok: boolean;
-  result?: unknown;
-  error?: string;
+export interface GitignoreAddPatternsResponse {
+  path: string;
+  added: string[];
+  existing: string[];
 }
 
-export interface SyncKanbanStateRequest {
-  tasks: KanbanTask[];
-  runs: KanbanTaskRun[];
+export interface GitIgnoreMatch {
+  path: string;
+  ignored: boolean;
+  source?: string | null;
+  line?: number | null;
+  pattern?: string | null;
 }
 
-export interface KanbanStartRunRequest {
-  taskId: string;
+export interface GitDiscardPathsRequest extends GitPathsRequest {
+  force: boolean;
 }
 
-export interface KanbanCompleteRunRequest {
-  runId: string;
-  status: KanbanRunCompletionStatus;
-  error?: string;
+export interface GitApplyPatchRequest extends GitRepoRequest {
+  /** Unified diff text. */
+  patch: string;
+  index?: boolean;
+  /** Three-way fallback that may leave conflict markers; implies `index`. */
+  threeWay?: boolean;
+  reverse?: boolean;
+  checkOnly?: boolean;
+}
+
+export interface GitApplyPatchResponse {
+  /** The patch applies without conflicts. */
+  applicable: boolean;
+  /** Only a three-way apply succeeds, and it would leave conflict markers. */
+  wouldConflict: boolean;
+  applied: boolean;
+  files: Array<{
+    path: string;
+    oldPath?: string | null;
+    additions?: number | null;
+    deletions?: number | null;
+    binary: boolean;
+  }>;
+  conflicts: string[];
+  output: string;
 }
 
-export interface KanbanRunLogsRequest {
-  runId: string;
-  cursor?: number;
-  limit?: number;
+export interface GitCleanRequest extends GitRepoRequest {
+  includeDirectories?: boolean;
+  includeIgnored?: boolean;
+  /** Defaults to `true`; removing files also needs `force`. */
+  dryRun?: boolean;
+  force?: boolean;
 }
 
-export interface KanbanStateSnapshot {
-  tasks: KanbanTask[];
-  runs: KanbanTaskRun[];
-  activeRunByPaneId: Record<string, string>;
+export interface GitCleanResponse {
+  dryRun: boolean;
+  /** Paths that would be or were removed; directories end in `/`. */
+  paths: string[];
+}
+
+export interface GitShowCommitRequest extends GitRepoRequest {
+  rev: string;
+  withPatch?: boolean;
+  pathFilter?: string;
+  renameThreshold?: number;
+  copyThreshold?: number;
+}
+
+export interface GitCommitFile {
+  path: string;
+  oldPath?: string | null;
+  status: string;
+  additions?: number | null;
+  deletions?: number | null;
+  binary: boolean;
+  patch: string;
+}
+
+export interface GitCommitDetail {
+  hash: string;
+  shortHash: string;
+  parents: string[];
+  authorName: string;
+  authorEmail: string;
+  authoredAt: number;
+  subject: string;
+  refs: string[];
+  signature?: GitCommitSignature | null;
+  committerName: string;
+  committerEmail: string;
+  committedAt: number;
+  message: string;
+  files: GitCommitFile[];
+  summary: { filesChanged: number; additions: number; deletions: number };
+  truncated: boolean;
+}
+
+export interface GitCommitGraphRequest extends GitRepoRequest {
+  /** Tips to walk from; omitted means the default branch plus every worktree's HEAD. */
+  refs?: string[];
+  limit?: number;
+}
+
+export interface GitGraphCommit {
+  hash: string;
+  shortHash: string;
+  parents: string[];
+  authorName: string;
+  authorEmail: string;
+  authoredAt: number;
+  subject: string;
+  refs: string[];
+}
+
+export interface GitCommitGraph {
+  refs: string[];
+  /** Topological order: every commit precedes its parents. */
+  commits: GitGraphCommit[];
+  truncated: boolean;
+}
+
+export interface GitNotesRequest extends GitRepoRequest {
+  rev: string;
+  /** `refs/notes/<name>` or `<name>`; defaults to `refs/notes/commits`. */
+  notesRef?: string;
+}
+
+export interface GitNotesAddRequest extends GitNotesRequest {
+  message: string;
+  force?: boolean;
+  append?: boolean;
+}
+
+export interface GitNoteResponse {
+  commit: string;
+  notesRef: string;
+  note: string | null;
+}
+
+export type GitArchiveFormat = "zip" | "tar" | "tar.gz";
+
+export interface GitArchiveRequest extends GitRepoRequest {
+  ref?: string;
+  format: GitArchiveFormat;
+  outputPath: string;
+  prefix?: string;
+  includeUncommitted?: boolean;
+  overwrite?: boolean;
+}
+
+export interface GitArchiveResponse {
+  outputPath: string;
+  format: GitArchiveFormat;
+  commit: string;
+  sizeBytes: number;
+}
+
+export type GitResetMode = "soft" | "mixed" | "hard";
+
+export interface GitResetRequest extends GitRepoRequest {
+  target?: string;
+  mode?: GitResetMode;
+  force?: boolean;
+  discardChanges?: boolean;
+}
+
+export interface GitResetResponse {
+  mode: GitResetMode;
+  from: string;
+  to: string;
+  discardedPaths: string[];
+  output: string;
+}
+
+export interface GitCommitRequest extends GitRepoRequest {
+  message: string;
+  sign?: boolean;
+  coAuthors?: string[];
+  trailers?: Array<{ key: string; value: string }>;
+  noVerify?: boolean;
+}
+
+export interface GitCommitAmendRequest extends GitRepoRequest {
+  /** Omit with `noEdit` to keep the current message. */
+  message?: string;
+  noEdit?: boolean;
+  /** Amend even when HEAD is already on a remote branch. */
+  force?: boolean;
+}
+
+export interface GitPullRequest extends GitRepoRequest {
+  rebase?: boolean;
+  autostash?: boolean;
+  remote?: string;
+  branch?: string;
+}
+
+export interface GitPullResponse {
+  outcome: "upToDate" | "fastForward" | "rebased" | "conflict";
+  from: string;
+  to: string;
+  output: string;
+  conflicts: string[];
+}
+
+export interface GitPushRequest extends GitRepoRequest {
+  noVerify?: boolean;
+  remote?: string;
+  refspec?: string;
+  forceWithLease?: boolean;
+  pushTags?: boolean;
+  setUpstream?: boolean;
+}
+
+export interface GitCloneOptions {
+  depth?: number;
+  blobless?: boolean;
+  singleBranch?: boolean;
+  branch?: string;
+}
+
+export interface CloneRepositoryRequest {
+  url: string;
+  destination: string;
+  options?: GitCloneOptions;
+}
+
+export interface InitRepositoryRequest {
+  path: string;
+  initialBranch?: string;
+  createInitialCommit?: boolean;
+  gitignoreTemplate?: string;
+}
+
+export interface GitCloneProgress {
+  phase: "counting" | "compressing" | "receiving" | "resolving" | "checkout";
+  percent: number;
+  current: number;
+  total: number;
+  line: string;
+}
+
+export interface MoveWorktreeRequest {
+  repoRoot: string;
+  worktreePath: string;
+  destination: string;
+}
+
+export interface RepairWorktreesRequest {
+  repoRoot: string;
+  paths?: string[];
+}
+
+export interface RepairWorktreesResponse {
+  repaired: Array<{ reason: string; path: string }>;
+  errors: string[];
+  worktrees: WorktreeEntry[];
+}
+
+export interface WorktreeMovedEvent {
+  workspaceId?: string | null;
+  from: string;
+  to: string;
+}
+
+export interface WorkspaceCandidate {
+  repo: RepoContext;
+  source: "clone" | "init";
+  originUrl?: string | null;
+  createdAtMs: number;
+}
+
+export interface GitFetchRemoteRequest extends GitRepoRequest {
+  remote: string;
+  prune?: boolean;
+  /** `true` fetches all tags, `false` none; omitted follows the remote's `tagOpt`. */
+  tags?: boolean;
+}
+
+export interface GitFetchUnshallowRequest extends GitRepoRequest {
+  remote?: string;
+  deepen?: number;
+  allBranches?: boolean;
+}
+
+export interface GitCreateBundleRequest extends GitRepoRequest {
+  bundlePath: string;
+  /** Revisions or ranges to include, e.g. `main` or `origin/main..feature`. */
+  revisions: string[];
+}
+
+export interface GitFetchFromBundleRequest extends GitRepoRequest {
+  bundlePath: string;
+  refspecs: string[];
+}
+
+export interface GitBundleHead {
+  commit: string;
+  refName: string;
+}
+
+export interface GitBundleResponse {
+  bundlePath: string;
+  heads: GitBundleHead[];
+  output: string;
+}
+
+export interface GitCheckoutBranchRequest extends GitRepoRequest {
+  branch: string;
+}
+
+export interface GitCreateBranchRequest extends GitRepoRequest {
+  branch: string;
+  baseRef?: string;
+  checkout?: boolean;
+}
+
+export interface GitDeleteBranchRequest extends GitRepoRequest {
+  branch: string;
+  force?: boolean;
+}
+
+export interface GitSetUpstreamRequest extends GitRepoRequest {
+  branch: string;
+  /** `<remote>/<branch>`; defaults to the same name on `origin` or the only remote. */
+  remoteBranch?: string;
+}
+
+export interface GitUnsetUpstreamRequest extends GitRepoRequest {
+  branch: string;
+}
+
+export interface GitStashEntry {
+  index: number;
+  /** `stash@{N}`. */
+  reference: string;
+  branch?: string | null;
+  message: string;
+  commit: string;
+  createdAt: number;
+}
+
+export interface GitStashPushRequest extends GitRepoRequest {
+  message?: string;
+  includeUntracked?: boolean;
+}
+
+export interface GitStashRequest extends GitRepoRequest {
+  index: number;
+  /** Also restore the staged state (`--index`); apply and pop only. */
+  restoreIndex?: boolean;
+}
+
+export interface StackBranchEntry {
+  branch: string;
+  parent: string;
+  trunk: string;
+  children: string[];
+  depth: number;
+  position: number;
+  stackSize: number;
+  worktreePath?: string | null;
+  needsRestack: boolean;
+}
+
+export interface StackSetParentRequest extends GitRepoRequest {
+  branch: string;
+  /** Omit to remove the branch from its stack. */
+  parent?: string;
+}
+
+export interface StackBranchRequest extends GitRepoRequest {
+  branch: string;
+}
+
+export interface StackRestackResponse {
+  restacked: string[];
+  upToDate: string[];
+}
+
+export interface StackPrAnnotation {
+  branch: string;
+  position: number;
+  prNumber?: number | null;
+  updated: boolean;
+}
+
+export interface GenerateChangelogRequest extends GitRepoRequest {
+  from: string;
+  /** Defaults to `HEAD`. */
+  to?: string;
+}
+
+export interface ChangelogEntry {
+  section: string;
+  title: string;
+  prNumber?: number | null;
+  author?: string | null;
+  commit: string;
+}
+
+export interface GenerateChangelogResponse {
+  from: string;
+  to: string;
+  markdown: string;
+  entries: ChangelogEntry[];
+}
+
+export interface GitLogRequest extends GitRepoRequest {
+  /** Revision or range such as `main..feature`; defaults to `HEAD`. */
+  range?: string;
+  path?: string;
+  author?: string;
+  skip?: number;
+  limit?: number;
+}
+
+export type GitSignatureStatus =
+  | "good"
+  | "goodUntrusted"
+  | "expiredSignature"
+  | "expiredKey"
+  | "revokedKey"
+  | "unverifiable"
+  | "bad";
+
+export interface GitCommitSignature {
+  status: GitSignatureStatus;
+  signer?: string | null;
+  key?: string | null;
+}
+
+export interface GitSigningConfig {
+  /** `gpg.format`: `openpgp`, `ssh` or `x509`. */
+  format: string;
+  signingKey?: string | null;
+  signByDefault: boolean;
+  program: string;
+  programAvailable: boolean;
+  keyAvailable: boolean;
+}
+
+export interface GitLogEntry {
+  hash: string;
+  shortHash: string;
+  parents: string[];
+  authorName: string;
+  authorEmail: string;
+  authoredAt: number;
+  subject: string;
+  refs: string[];
+  /** `null` for unsigned commits. */
+  signature?: GitCommitSignature | null;
+}
+
+export interface GitLogPage {
+  entries: GitLogEntry[];
+  skip: number;
+  limit: number;
+  hasMore: boolean;
+}
+
+export interface GitFileHistoryRequest extends GitRepoRequest {
+  path: string;
+  limit?: number;
+  withPatch?: boolean;
+}
+
+/** A commit touching the file, with the path it had in that commit (history follows renames). */
+export interface GitFileHistoryEntry extends GitLogEntry {
+  path: string;
+  oldPath?: string | null;
+  status: string;
+  patch?: string | null;
+}
+
+export interface GitFileHistory {
+  path: string;
+  entries: GitFileHistoryEntry[];
+  hasMore: boolean;
+  patchesTruncated: boolean;
+}
+
+export interface GitBlameRequest extends GitRepoRequest {
+  path: string;
+  /** Blame at this revision; omit for the working tree. */
+  rev?: string;
+}
+
+export interface GitBlameLine {
+  lineNumber: number;
+  originalLineNumber: number;
+  commit: string;
+  author: string;
+  authorEmail: string;
+  authorTime: number;
+  summary: string;
+  /** Set when the line came from a file that was later renamed. */
+  previousPath?: string | null;
+  isBoundary: boolean;
+  content: string;
+}
+
+export interface GitBlameResponse {
+  path: string;
+  rev?: string | null;
+  lines: GitBlameLine[];
+}
+
+export interface GitCherryPickRequest extends GitRepoRequest {
+  commits: string[];
+  noCommit?: boolean;
+}
+
+export interface GitCherryPickState {
+  inProgress: boolean;
+  currentCommit?: string | null;
+  remaining: number;
+  conflicts: string[];
+}
+
+export interface GitCherryPickResponse {
+  /** False when the sequence stopped on conflicts. */
+  completed: boolean;
+  output: string;
+  state: GitCherryPickState;
+}
+
+export type GitRebasePlanAction = "pick" | "reword" | "edit" | "squash" | "fixup" | "drop";
+
+export interface GitRebasePlanEntry {
+  action: GitRebasePlanAction;
+  commit: string;
+  /** Required for `reword`. */
+  message?: string;
+}
+
+export interface GitRebaseStartRequest extends GitRepoRequest {
+  onto: string;
+  /** Runs an interactive rebase with this todo list instead of a plain one. */
+  interactivePlan?: GitRebasePlanEntry[];
+  autostash?: boolean;
+}
+
+export interface GitRebaseState {
+  inProgress: boolean;
+  interactive: boolean;
+  branch?: string | null;
+  onto?: string | null;
+  currentStep: number;
+  totalSteps: number;
+  currentCommit?: string | null;
+  conflicts: string[];
+}
+
+export interface GitRebaseResponse {
+  completed: boolean;
+  output: string;
+  state: GitRebaseState;
+}
+
+export interface GitMergeRequest extends GitRepoRequest {
+  ref: string;
+  noFf?: boolean;
+  squash?: boolean;
+}
+
+export interface GitMergeResponse {
+  completed: boolean;
+  output: string;
+  conflicts: string[];
+}
+
+export type GitConflictKind =
+  | "bothModified"
+  | "bothAdded"
+  | "deletedByUs"
+  | "deletedByThem"
+  | "addedByUs"
+  | "addedByThem"
+  | "bothDeleted";
+
+export interface GitConflictFile {
+  path: string;
+  kind: GitConflictKind;
+  hasBase: boolean;
+  hasOurs: boolean;
+  hasTheirs: boolean;
+}
+
+export interface GitConflictPathRequest extends GitRepoRequest {
+  path: string;
+}
+
+/** Each side is `null` when that stage is missing; all are `null` for binary files. */
+export interface GitConflictVersions {
+  path: string;
+  kind: GitConflictKind;
+  binary: boolean;
+  base?: string | null;
+  ours?: string | null;
+  theirs?: string | null;
+  working?: string | null;
+}
+
+export interface GitConflictResolveRequest extends GitConflictPathRequest {
+  strategy: "ours" | "theirs";
+}
+
+export interface GitConflictWriteRequest extends GitConflictPathRequest {
+  content: string;
+  markResolved?: boolean;
+}
+
+export interface GitConflictMarkResolvedRequest extends GitRepoRequest {
+  paths: string[];
+  /** Stage the paths even if they still contain conflict markers. */
+  force?: boolean;
+}
+
+export interface GitLfsFile {
+  path: string;
+  oid?: string | null;
+  /** False while the working tree still holds the pointer file. */
+  downloaded: boolean;
+}
+
+export interface GitLfsStatus {
+  installed: boolean;
+  version?: string | null;
+  usesLfs: boolean;
+  files: GitLfsFile[];
+}
+
+export interface GitLfsPullRequest extends GitRepoRequest {
+  /** Limits the pull to these paths or patterns. */
+  include?: string[];
+}
+
+export interface GitDiffRefsRequest extends GitRepoRequest {
+  base: string;
+  head: string;
+  path?: string;
+  /** Diff from the merge base of `base` and `head` (the default), like `base...head`. */
+  mergeBase?: boolean;
+  renameThreshold?: number;
+  copyThreshold?: number;
+  intraline?: boolean;
+}
+
+export interface GitRefDiffFile {
+  path: string;
+  oldPath?: string | null;
+  /** `git diff --name-status` letter, e.g. `M`, `A`, `D`, `R`. */
+  status: string;
+  additions?: number | null;
+  deletions?: number | null;
+  binary: boolean;
+  patch: string;
+  intraline?: IntralineChange[] | null;
+}
+
+export interface GitRefDiffSummary {
+  filesChanged: number;
+  additions: number;
+  deletions: number;
+}
+
+export interface GitDiffRefsResponse {
+  base: string;
+  head: string;
+  mergeBase?: string | null;
+  files: GitRefDiffFile[];
+  summary: GitRefDiffSummary;
+  /** Patches were cut off at the output size cap. */
+  truncated: boolean;
+}
+
+/** Unstaged changes by default; `staged` diffs the index, and `base`/`head` compare against revisions. */
+export interface GitDiffStatRequest extends GitRepoRequest {
+  staged?: boolean;
+  base?: string;
+  /** Requires `base`; cannot be combined with `staged`. */
+  head?: string;
+}
+
+export interface GitDiffStatEntry {
+  path: string;
+  oldPath?: string | null;
+  additions?: number | null;
+  deletions?: number | null;
+  binary: boolean;
+}
+
+export interface GitDiffStatResponse {
+  files: GitDiffStatEntry[];
+  summary: GitRefDiffSummary;
+}
+
+export interface BranchNamePolicy {
+  /** Regex the whole branch name must match. */
+  pattern?: string | null;
+  requiredPrefixes: string[];
+  maxLength?: number | null;
+  forbiddenCharacters?: string | null;
+}
+
+export interface BranchPolicyRequest {
+  /** Omit for the default policy applied to repos without their own. */
+  repoRoot?: string;
+}
+
+export interface SetBranchPolicyRequest extends BranchPolicyRequest {
+  /** `null` clears the policy. */
+  policy: BranchNamePolicy | null;
+}
+
+export interface CheckBranchNameRequest extends GitRepoRequest {
+  branch: string;
+}
+
+export interface BranchPolicyViolation {
+  rule: "pattern" | "prefix" | "maxLength" | "forbiddenCharacters";
+  message: string;
+}
+
+/** 1-based inclusive range: worktree lines when staging, index lines when unstaging. */
+export interface GitLineRangeRequest extends GitRepoRequest {
+  path: string;
+  startLine: number;
+  endLine: number;
+}
+
+export interface GitReflogRequest extends GitRepoRequest {
+  /** Defaults to `HEAD`. */
+  reference?: string;
+  limit?: number;
+}
+
+export interface GitReflogEntry {
+  /** e.g. `HEAD@{3}`. */
+  selector: string;
+  index: number;
+  hash: string;
+  shortHash: string;
+  action: string;
+  message: string;
+  subject: string;
+  recordedAt: number;
+}
+
+/** Creates `branch` at `target`, typically a reflog hash of lost work. */
+export interface GitRecoverRefRequest extends GitRepoRequest {
+  target: string;
+  branch: string;
+  checkout?: boolean;
+}
+
+export interface GitBisectStartRequest extends GitRepoRequest {
+  /** Defaults to `HEAD` when `good` is given. */
+  bad?: string;
+  good?: string[];
+}
+
+export interface GitBisectMarkRequest extends GitRepoRequest {
+  /** Defaults to the checked-out candidate. */
+  rev?: string;
+}
+
+export interface GitBisectCommit {
+  hash: string;
+  subject: string;
+}
+
+export interface GitBisectStatus {
+  active: boolean;
+  bad?: string | null;
+  good: string[];
+  skipped: string[];
+  candidate?: GitBisectCommit | null;
+  remainingRevisions?: number | null;
+  remainingSteps?: number | null;
+  /** Set once the bisect has narrowed down the culprit. */
+  firstBad?: GitBisectCommit | null;
+  output?: string | null;
+}
+
+export type GitMaintenanceTask = "gc" | "maintenanceRun" | "prune" | "commitGraph";
+
+export interface GitMaintenanceRequest extends GitRepoRequest {
+  tasks: GitMaintenanceTask[];
+}
+
+export interface GitMaintenanceTaskResult {
+  task: GitMaintenanceTask;
+  success: boolean;
+  output: string;
+  durationMs: number;
+}
+
+/** Payload of the `git:maintenance-progress` event. */
+export interface GitMaintenanceProgressEvent {
+  repoRoot: string;
+  task: GitMaintenanceTask;
+  line: string;
+}
+
+export type GitConfigScope = "system" | "global" | "local" | "worktree" | "command";
+
+export interface GitConfigEntry {
+  key: string;
+  value?: string | null;
+  scope?: GitConfigScope | null;
+  /** Credentials and tokens are masked in `value`. */
+  redacted: boolean;
+}
+
+export interface GitConfigGetRequest extends GitRepoRequest {
+  key: string;
+  /** Omit for the effective value across all scopes. */
+  scope?: GitConfigScope;
+}
+
+export interface GitConfigSetRequest extends GitRepoRequest {
+  key: string;
+  /** Omit to unset the key. */
+  value?: string;
+  /** Defaults to `local`; only `global`, `local` and `worktree` are writable. */
+  scope?: GitConfigScope;
+}
+
+export interface GitConfigListRequest extends GitRepoRequest {
+  scope?: GitConfigScope;
+}
+
+/** `allow` honors `noVerify`, `enforce` rejects it, and `skip` always bypasses hooks. */
+export type GitHookPolicy = "allow" | "enforce" | "skip";
+
+export type GitHookManager = "husky" | "lefthook" | "preCommit";
+
+export interface GitHookEntry {
+  name: string;
+  path: string;
+  executable: boolean;
+  manager?: GitHookManager | null;
+}
+
+export interface GitHookManagerInfo {
+  manager: GitHookManager;
+  configured: boolean;
+  installed: boolean;
+}
+
+export interface GitHooksInfo {
+  hooksDir: string;
+  /** `core.hooksPath`, when set. */
+  hooksPath?: string | null;
+  hooks: GitHookEntry[];
+  managers: GitHookManagerInfo[];
+  policy: GitHookPolicy;
+}
+
+export interface SetGitHookPolicyRequest extends GitRepoRequest {
+  policy: GitHookPolicy;
+}
+
+export interface GitHubListRequest extends GitRepoRequest {
+  limit?: number;
+}
+
+export interface GitHubPrRequest extends GitRepoRequest {
+  number: number;
+}
+
+export interface GitHubPrCommentRequest extends GitHubPrRequest {
+  body: string;
+}
+
+export interface GitHubPrCreateRequest extends GitRepoRequest {
+  title: string;
+  body: string;
+  base: string;
+  head?: string;
+  draft?: boolean;
+  labels?: string[];
+  reviewers?: string[];
+}
+
+export interface GitHubPrMergeRequest extends GitHubPrRequest {
+  deleteBranch?: boolean;
+}
+
+export interface GitHubIssueRequest extends GitRepoRequest {
+  number: number;
+}
+
+export interface GitHubIssueCommentRequest extends GitHubIssueRequest {
+  body: string;
+}
+
+export interface GitHubIssueEditLabelsRequest extends GitHubIssueRequest {
+  addLabels: string[];
+  removeLabels: string[];
+}
+
+export interface GitHubIssueEditAssigneesRequest extends GitHubIssueRequest {
+  addAssignees: string[];
+  removeAssignees: string[];
+}
+
+export interface GitHubRunRequest extends GitRepoRequest {
+  runId: number;
+}
+
+export interface GitHubAccount {
+  hostname: string;
+  login: string;
+  /** The account `gh` itself currently uses for this host. */
+  active: boolean;
+}
+
+export interface GitHubAccountsResponse {
+  accounts: GitHubAccount[];
+  /** Account pinned to this repo, if any; otherwise `gh`'s active account is used. */
+  selectedLogin?: string | null;
+}
+
+export interface GitHubSetRepoAccountRequest extends GitRepoRequest {
+  /** Omit or leave empty to clear the repo's pinned account. */
+  login?: string;
+  hostname?: string;
+}
+
+export interface GitHubAuditEntry {
+  atMs: number;
+  repoRoot: string;
+  account: string;
+  action: string;
+  succeeded: boolean;
+}
+
+export interface GitHubOrgsRequest {
+  /** Resolves the account pinned to this repo; `gh`'s active account otherwise. */
+  repoRoot?: string;
+}
+
+export interface GitHubOrgSummary {
+  login: string;
+  description?: string | null;
+  avatarUrl?: string | null;
+  /** Served from the cache after a failed refresh. */
+  stale: boolean;
+}
+
+export interface GitHubReposRequest {
+  repoRoot?: string;
+  /** Org or user login; defaults to the signed-in account. */
+  owner?: string;
+  query?: string;
+  page?: number;
+  perPage?: number;
+}
+
+export interface GitHubRepoSummary {
+  fullName: string;
+  name: string;
+  description?: string | null;
+  private: boolean;
+  fork: boolean;
+  archived: boolean;
+  defaultBranch?: string | null;
+  cloneUrl: string;
+  sshUrl: string;
+  htmlUrl: string;
+  updatedAt?: string | null;
+}
+
+export interface GitHubRepoPage {
+  repos: GitHubRepoSummary[];
+  page: number;
+  perPage: number;
+  hasNextPage: boolean;
+  totalCount?: number | null;
+  stale: boolean;
+}
+
+export interface GitHubReleaseCreateRequest extends GitRepoRequest {
+  tag: string;
+  target?: string;
+  title?: string;
+  /** Takes precedence over `previousTag`, which generates notes from the changelog since that tag. */
+  notes?: string;
+  previousTag?: string;
+  draft?: boolean;
+  prerelease?: boolean;
+}
+
+export interface GitHubStaleRead {
+  repoRoot: string;
+  command: string;
+  fetchedAtMs: number;
+  servedAtMs: number;
+}
+
+export interface GitHubNetworkStatus {
+  online: boolean;
+  offlineSinceMs?: number | null;
+  lastError?: string | null;
+  /** Cached responses served while GitHub was unreachable. */
+  staleReads: GitHubStaleRead[];
+}
+
+export interface AutomationWorkspaceSnapshot {
+  workspaceId: string;
+  name: string;
+  repoRoot: string;
+  worktreePath: string;
+  runtimePaneIds: string[];
+}
+
+export interface AutomationReportRequest {
+  jobId: string;
+  ok: boolean;
+  result?: unknown;
+  error?: string;
+}
+
+export interface SyncKanbanStateRequest {
+  tasks: KanbanTask[];
+  runs: KanbanTaskRun[];
+}
+
+export interface KanbanStartRunRequest {
+  taskId: string;
+}
+
+export interface KanbanCompleteRunRequest {
+  runId: string;
+  status: KanbanRunCompletionStatus;
+  error?: string;
+}
+
+export interface KanbanRunLogsRequest {
+  runId: string;
+  cursor?: number;
+  limit?: number;
+}
+
+export interface KanbanStateSnapshot {
+  tasks: KanbanTask[];
+  runs: KanbanTaskRun[];
+  activeRunByPaneId: Record<string, string>;
 }
 
 export type FrontendAutomationRequest =
@@ -508,3 +1975,272 @@ export type FrontendAutomationRequest =
       jobId: string;
       worktreePath: string;
     };
+
+export interface DetectTestCommandRequest {
+  worktreePath: string;
+}
+
+export interface TestCommandDetection {
+  command: string;
+  framework: string;
+}
+
+export interface RunTestsRequest {
+  worktreePath: string;
+  /** Overrides the detected command. */
+  command?: string;
+  /** Runs inside this pane when its shell has integration; otherwise in the background. */
+  paneId?: string;
+}
+
+export type TestRunStatus = "running" | "passed" | "failed" | "error";
+
+export interface TestSummary {
+  passed: number;
+  failed: number;
+  skipped: number;
+  failures: string[];
+}
+
+export interface TestRun {
+  id: string;
+  worktreePath: string;
+  command: string;
+  framework: string;
+  paneId?: string | null;
+  status: TestRunStatus;
+  exitCode?: number | null;
+  summary?: TestSummary | null;
+  startedAtMs: number;
+  finishedAtMs?: number | null;
+}
+
+export interface TestRunDetail {
+  run: TestRun;
+  output: string;
+}
+
+export interface TestRunRequest {
+  runId: string;
+}
+
+export interface ListTestRunsRequest {
+  worktreePath?: string;
+}
+
+export interface TestRunOutputEvent {
+  runId: string;
+  chunk: string;
+}
+
+export interface NotificationRule {
+  sound?: string | null;
+  notify: boolean;
+}
+
+export interface QuietHours {
+  /** Minutes after midnight, 0-1439; a start after the end wraps past midnight. */
+  startMinute: number;
+  endMinute: number;
+  utcOffsetMinutes: number;
+}
+
+export interface WorkspaceNotificationOverride {
+  muted: boolean;
+  bell?: NotificationRule | null;
+  commandFinished?: NotificationRule | null;
+  jobFailed?: NotificationRule | null;
+  trigger?: NotificationRule | null;
+}
+
+export interface NotificationPolicy {
+  enabled: boolean;
+  bell: NotificationRule;
+  commandFinished: NotificationRule;
+  jobFailed: NotificationRule;
+  trigger: NotificationRule;
+  longCommandThresholdMs?: number | null;
+  quietHours?: QuietHours | null;
+  /** Keyed by workspace id. */
+  workspaceOverrides: Record<string, WorkspaceNotificationOverride>;
+}
+
+/** Payload of the `notification:route` event. */
+export interface NotificationEvent {
+  kind: "bell" | "commandFinished" | "jobFailed" | "trigger";
+  paneId?: string | null;
+  workspaceId?: string | null;
+  sound?: string | null;
+  notify: boolean;
+  title: string;
+  body: string;
+}
+
+export interface DiffWorkspaceEnvRequest {
+  /** Workspace id or worktree path. */
+  workspaceA: string;
+  workspaceB: string;
+}
+
+export interface WorkspaceEnvSide {
+  workspaceId?: string | null;
+  worktreePath: string;
+  shell: string;
+  /** Tool versions are only probed in trusted repos. */
+  toolsProbed: boolean;
+}
+
+export interface WorkspaceEnvDifference {
+  category: "shell" | "env" | "toolVersionFile" | "toolVersion" | "gitConfig";
+  key: string;
+  a?: string | null;
+  b?: string | null;
+}
+
+export interface WorkspaceEnvDiff {
+  a: WorkspaceEnvSide;
+  b: WorkspaceEnvSide;
+  differences: WorkspaceEnvDifference[];
+  identicalCount: number;
+}
+
+export type MaintenanceTask = "maintenance" | "gc" | "worktreePrune" | "cacheCleanup";
+
+export interface MaintenanceSchedule {
+  repoRoot: string;
+  enabled: boolean;
+  /** Minutes between runs of each task. */
+  intervals: Partial<Record<MaintenanceTask, number>>;
+  lastRunMs: Partial<Record<MaintenanceTask, number>>;
+}
+
+export interface SetMaintenanceScheduleRequest {
+  repoRoot: string;
+  enabled?: boolean;
+  intervals: Partial<Record<MaintenanceTask, number>>;
+}
+
+export interface ListMaintenanceReportsRequest {
+  repoRoot?: string;
+}
+
+export interface RunMaintenanceRequest {
+  repoRoot: string;
+  /** Defaults to everything but `gc`. */
+  tasks?: MaintenanceTask[];
+}
+
+/** Also the payload of the `maintenance:report` event. */
+export interface MaintenanceReport {
+  id: string;
+  repoRoot: string;
+  task: MaintenanceTask;
+  trigger: "scheduled" | "manual";
+  success: boolean;
+  output: string;
+  startedAtMs: number;
+  durationMs: number;
+}
+
+export type RepoTrustStatus = "unknown" | "trusted" | "untrusted" | "changed";
+
+/** The repo's project config file and whether its commands may run. */
+export interface ProjectConfig {
+  repoRoot: string;
+  path: string;
+  exists: boolean;
+  trust: RepoTrustStatus;
+  /** `config` leaves out executable entries until the repo is trusted. */
+  readOnly: boolean;
+  needsPrompt: boolean;
+  config: unknown;
+  /** Entry name mapped to the command it would run. */
+  executableEntries: Record<string, string>;
+  configSha256?: string | null;
+  error?: string | null;
+}
+
+export interface SetRepoTrustedRequest {
+  repoRoot: string;
+  trusted: boolean;
+  /** Pins the decision to the config the user reviewed. */
+  configSha256?: string;
+}
+
+export type SshTunnelDirection = "local" | "remote";
+
+export type SshTunnelStatus = "starting" | "running" | "closed" | "failed";
+
+export interface OpenSshTunnelRequest {
+  workspaceId: string;
+  host: string;
+  /** `local` forwards `localPort` to `targetHost:remotePort` via `host` (`-L`); `remote` is `-R`. */
+  direction: SshTunnelDirection;
+  localPort: number;
+  remotePort: number;
+  /** Defaults to `localhost`. */
+  targetHost?: string;
+  sshPort?: number;
+}
+
+/** Also the payload of the `ssh-tunnel:status` event. */
+export interface SshTunnel {
+  id: string;
+  workspaceId: string;
+  host: string;
+  direction: SshTunnelDirection;
+  localPort: number;
+  remotePort: number;
+  targetHost: string;
+  status: SshTunnelStatus;
+  pid: number;
+  error?: string | null;
+  startedAtMs: number;
+}
+
+export interface ListSshTunnelsRequest {
+  workspaceId?: string;
+}
+
+/** Regex watched against pane output lines; scoped to a pane or workspace when set. */
+export interface PaneTrigger {
+  id: string;
+  name: string;
+  pattern: string;
+  caseInsensitive: boolean;
+  paneId?: string | null;
+  workspaceId?: string | null;
+  notify: boolean;
+  webhookUrl?: string | null;
+  /** Disables the trigger after its first match. */
+  once: boolean;
+  cooldownMs: number;
+  enabled: boolean;
+  fireCount: number;
+}
+
+/** Omit `id` to create a trigger; pass it to replace an existing one. */
+export interface SetPaneTriggerRequest {
+  id?: string;
+  name: string;
+  pattern: string;
+  caseInsensitive?: boolean;
+  paneId?: string;
+  workspaceId?: string;
+  notify?: boolean;
+  webhookUrl?: string;
+  once?: boolean;
+  cooldownMs?: number;
+  enabled?: boolean;
+}
+
+/** Payload of the `pane:trigger` event. */
+export interface PaneTriggerEvent {
+  triggerId: string;
+  name: string;
+  paneId: string;
+  workspaceId?: string | null;
+  line: string;
+  matched: string;
+  firedAtMs: number;
+}
@@ -0,0 +1,672 @@
+//! Typed client for the SuperVibing automation bridge.
+//!
+//! The wire format is described by `schema/automation-v1.json`, which also generates the
+//! TypeScript client in `packages/automation-client`.
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    env, fmt, fs,
+    io::{Read, Write},
+    net::{SocketAddr, TcpStream, ToSocketAddrs},
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant},
+};
+
+pub const BIND_ENV: &str = "SUPERVIBING_AUTOMATION_BIND";
+pub const TOKEN_ENV: &str = "SUPERVIBING_AUTOMATION_TOKEN";
+pub const PORT_RANGE_ENV: &str = "SUPERVIBING_AUTOMATION_PORT_RANGE";
+pub const DISCOVERY_FILE_ENV: &str = "SUPERVIBING_AUTOMATION_DISCOVERY_FILE";
+pub const DISCOVERY_FILE_NAME: &str = "supervibing-automation.json";
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+pub const DEFAULT_PORT_RANGE: (u16, u16) = (47631, 47641);
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+const PROBE_TIMEOUT: Duration = Duration::from_millis(250);
+const JOB_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthResponse {
+    pub status: String,
+    pub bind: String,
+    pub queued_jobs: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspaceSnapshot {
+    pub workspace_id: String,
+    pub name: String,
+    pub repo_root: String,
+    pub worktree_path: String,
+    pub runtime_pane_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkspacesResponse {
+    pub workspaces: Vec<WorkspaceSnapshot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Succeeded,
+    Failed,
+}
+
+impl JobStatus {
+    pub fn is_finished(self) -> bool {
+        matches!(self, Self::Succeeded | Self::Failed)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+// Variant names double as the wire values (`newBranch`, ...).
+#[allow(clippy::enum_variant_names)]
+pub enum WorktreeCreateMode {
+    NewBranch,
+    ExistingBranch,
+    /// `branch` is `<remote>/<name>`: fetched first, then checked out as a tracking branch.
+    RemoteBranch,
+}
+
+/// Command payload for `POST /v1/commands`. Variant fields stay snake_case on the wire.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", tag = "action")]
+pub enum CommandRequest {
+    CreatePanes {
+        workspace_id: String,
+        pane_count: u16,
+    },
+    CreateWorktree {
+        workspace_id: String,
+        mode: WorktreeCreateMode,
+        branch: String,
+        base_ref: Option<String>,
+        open_after_create: Option<bool>,
+    },
+    CreateBranch {
+        workspace_id: String,
+        branch: String,
+        base_ref: Option<String>,
+        checkout: Option<bool>,
+    },
+    RunCommand {
+        workspace_id: String,
+        command: String,
+        execute: Option<bool>,
+    },
+    ScaffoldFile {
+        workspace_id: String,
+        template: String,
+        target_path: String,
+        variables: Option<HashMap<String, String>>,
+        stage: Option<bool>,
+    },
+    /// Pushes the workspace branch and waits for its CI runs; the job result carries
+    /// `passed`, `conclusion`, and `failures`.
+    RunCiAndWait {
+        workspace_id: String,
+        workflow: Option<String>,
+        remote: Option<String>,
+        timeout_secs: Option<u64>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitCommandResponse {
+    pub job_id: String,
+    pub status: JobStatus,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobRecord {
+    pub job_id: String,
+    pub status: JobStatus,
+    pub request: CommandRequest,
+    pub result: Option<serde_json::Value>,
+    pub error: Option<String>,
+    pub created_at_ms: u64,
+    pub started_at_ms: Option<u64>,
+    pub finished_at_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveryRecord {
+    pub bind: String,
+    pub pid: u32,
+    pub token_required: bool,
+    pub started_at_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(std::io::Error),
+    Http { status: u16, message: String },
+    Decode(String),
+    Discovery(String),
+    Timeout(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "io error: {err}"),
+            Self::Http { status, message } => write!(f, "http {status}: {message}"),
+            Self::Decode(message) => write!(f, "decode error: {message}"),
+            Self::Discovery(message) => write!(f, "discovery error: {message}"),
+            Self::Timeout(message) => write!(f, "timeout: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    bind: String,
+    token: Option<String>,
+    timeout: Duration,
+}
+
+impl Client {
+    pub fn new(bind: impl Into<String>) -> Self {
+        Self {
+            bind: bind.into(),
+            token: None,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Finds a running bridge: `SUPERVIBING_AUTOMATION_BIND`, then the discovery file written by
+    /// the app, then a health probe over the fallback port range. The token comes from
+    /// `SUPERVIBING_AUTOMATION_TOKEN` when set.
+    pub fn discover() -> Result<Self> {
+        let token = env_value(TOKEN_ENV);
+        let mut candidates = Vec::new();
+        if let Some(bind) = env_value(BIND_ENV) {
+            candidates.push(bind);
+        }
+        if let Some(record) = read_discovery_record() {
+            candidates.push(record.bind);
+        }
+        let (start, end) = env_value(PORT_RANGE_ENV)
+            .and_then(|value| parse_port_range(&value))
+            .unwrap_or(DEFAULT_PORT_RANGE);
+        for host in [DEFAULT_HOST, "[::1]"] {
+            candidates.extend((start..=end).map(|port| format!("{host}:{port}")));
+        }
+
+        let mut seen = Vec::new();
+        for bind in candidates {
+            if seen.contains(&bind) {
+                continue;
+            }
+            seen.push(bind.clone());
+            let mut client = Self::new(bind).with_timeout(PROBE_TIMEOUT);
+            if let Some(token) = &token {
+                client = client.with_token(token.clone());
+            }
+            if client.health().is_ok_and(|health| health.status == "ok") {
+                return Ok(client.with_timeout(DEFAULT_TIMEOUT));
+            }
+        }
+
+        Err(Error::Discovery(
+            "no automation bridge answered on the configured, discovered, or default binds"
+                .to_string(),
+        ))
+    }
+
+    pub fn bind(&self) -> &str {
+        &self.bind
+    }
+
+    pub fn health(&self) -> Result<HealthResponse> {
+        self.request("GET", "/v1/health", None)
+    }
+
+    pub fn workspaces(&self) -> Result<Vec<WorkspaceSnapshot>> {
+        self.request::<WorkspacesResponse>("GET", "/v1/workspaces", None)
+            .map(|response| response.workspaces)
+    }
+
+    pub fn submit_command(&self, command: &CommandRequest) -> Result<SubmitCommandResponse> {
+        let body = serde_json::to_vec(command).map_err(|err| Error::Decode(err.to_string()))?;
+        self.request("POST", "/v1/commands", Some(&body))
+    }
+
+    pub fn get_job(&self, job_id: &str) -> Result<JobRecord> {
+        self.request("GET", &format!("/v1/jobs/{job_id}"), None)
+    }
+
+    /// Polls a job until it succeeds or fails.
+    pub fn wait_for_job(&self, job_id: &str, timeout: Duration) -> Result<JobRecord> {
+        let started_at = Instant::now();
+        loop {
+            let job = self.get_job(job_id)?;
+            if job.status.is_finished() {
+                return Ok(job);
+            }
+            if started_at.elapsed() >= timeout {
+                return Err(Error::Timeout(format!(
+                    "job {job_id} is still {:?}",
+                    job.status
+                )));
+            }
+            thread::sleep(JOB_POLL_INTERVAL);
+        }
+    }
+
+    fn request<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        body: Option<&[u8]>,
+    ) -> Result<T> {
+        let address = self
+            .bind
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| Error::Discovery(format!("`{}` did not resolve", self.bind)))?;
+        let mut stream = TcpStream::connect_timeout(&address, self.timeout)?;
+        stream.set_read_timeout(Some(self.timeout))?;
+        stream.set_write_timeout(Some(self.timeout))?;
+
+        let body = body.unwrap_or_default();
+        let mut head = format!(
+            "{method} {path} HTTP/1.1\r\nHost: {}\r\nAccept: application/json\r\nConnection: close\r\nContent-Length: {}\r\n",
+            self.bind,
+            body.len()
+        );
+        if !body.is_empty() {
+            head.push_str("Content-Type: application/json\r\n");
+        }
+        if let Some(token) = &self.token {
+            head.push_str(&format!("Authorization: Bearer {token}\r\n"));
+        }
+        head.push_str("\r\n");
+        let mut request = head.into_bytes();
+        request.extend_from_slice(body);
+        stream.write_all(&request)?;
+        stream.flush()?;
+
+        // The bridge answers one request per connection and closes it afterwards.
+        let mut response = Vec::new();
+        stream.read_to_end(&mut response)?;
+        let (status, body) = parse_http_response(&response)?;
+        if !(200..300).contains(&status) {
+            let message = serde_json::from_slice::<ErrorResponse>(body)
+                .map(|error| error.error)
+                .unwrap_or_else(|_| String::from_utf8_lossy(body).to_string());
+            return Err(Error::Http { status, message });
+        }
+        serde_json::from_slice(body).map_err(|err| Error::Decode(err.to_string()))
+    }
+}
+
+pub fn discovery_file_path() -> PathBuf {
+    if let Some(path) = env_value(DISCOVERY_FILE_ENV) {
+        return PathBuf::from(path);
+    }
+    env_value("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(env::temp_dir)
+        .join(DISCOVERY_FILE_NAME)
+}
+
+/// Reads the discovery file, ignoring it unless it is trusted and points at a loopback bind.
+pub fn read_discovery_record() -> Option<DiscoveryRecord> {
+    read_discovery_record_at(&discovery_file_path())
+}
+
+fn read_discovery_record_at(path: &Path) -> Option<DiscoveryRecord> {
+    if !discovery_file_trusted(path) {
+        return None;
+    }
+    let text = fs::read_to_string(path).ok()?;
+    let record = serde_json::from_str::<DiscoveryRecord>(&text).ok()?;
+    is_loopback_bind(&record.bind).then_some(record)
+}
+
+// The file may live in a shared temp dir, so only a regular file owned by this user and not
+// writable by anyone else is trusted to pick the bind the token is sent to.
+#[cfg(unix)]
+fn discovery_file_trusted(path: &Path) -> bool {
+    use std::os::unix::fs::MetadataExt;
+    // SAFETY: getuid has no preconditions and cannot fail.
+    let uid = unsafe { libc::getuid() };
+    fs::symlink_metadata(path).is_ok_and(|metadata| {
+        metadata.file_type().is_file() && metadata.uid() == uid && metadata.mode() & 0o022 == 0
+    })
+}
+
+#[cfg(not(unix))]
+fn discovery_file_trusted(path: &Path) -> bool {
+    fs::symlink_metadata(path).is_ok_and(|metadata| metadata.file_type().is_file())
+}
+
+fn is_loopback_bind(bind: &str) -> bool {
+    bind.trim()
+        .parse::<SocketAddr>()
+        .is_ok_and(|address| address.ip().is_loopback())
+}
+
+fn env_value(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+fn parse_port_range(value: &str) -> Option<(u16, u16)> {
+    let (start, end) = value.trim().split_once('-')?;
+    let (start, end) = (
+        start.trim().parse::<u16>().ok()?,
+        end.trim().parse::<u16>().ok()?,
+    );
+    (start > 0 && start <= end).then_some((start, end))
+}
+
+fn parse_http_response(response: &[u8]) -> Result<(u16, &[u8])> {
+    let split = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(|| Error::Decode("response has no header terminator".to_string()))?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let status = head
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(|| Error::Decode(format!("invalid status line: {head}")))?;
+    Ok((status, &response[split + 4..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    const SCHEMA: &str = include_str!("../schema/automation-v1.json");
+
+    fn schema_fields(schema: &serde_json::Value, pointer: &str) -> Vec<String> {
+        let mut fields = schema
+            .pointer(pointer)
+            .and_then(serde_json::Value::as_object)
+            .map(|fields| fields.keys().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+        fields.sort();
+        fields
+    }
+
+    fn value_fields(value: serde_json::Value, skip: &[&str]) -> Vec<String> {
+        let mut fields = value
+            .as_object()
+            .map(|fields| {
+                fields
+                    .keys()
+                    .filter(|key| !skip.contains(&key.as_str()))
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        fields.sort();
+        fields
+    }
+
+    fn sample_commands() -> Vec<CommandRequest> {
+        vec![
+            CommandRequest::CreatePanes {
+                workspace_id: "ws".to_string(),
+                pane_count: 2,
+            },
+            CommandRequest::CreateWorktree {
+                workspace_id: "ws".to_string(),
+                mode: WorktreeCreateMode::NewBranch,
+                branch: "feat".to_string(),
+                base_ref: None,
+                open_after_create: None,
+            },
+            CommandRequest::CreateBranch {
+                workspace_id: "ws".to_string(),
+                branch: "feat".to_string(),
+                base_ref: None,
+                checkout: None,
+            },
+            CommandRequest::RunCommand {
+                workspace_id: "ws".to_string(),
+                command: "ls".to_string(),
+                execute: Some(true),
+            },
+            CommandRequest::ScaffoldFile {
+                workspace_id: "ws".to_string(),
+                template: "component".to_string(),
+                target_path: "src/a.tsx".to_string(),
+                variables: None,
+                stage: None,
+            },
+            CommandRequest::RunCiAndWait {
+                workspace_id: "ws".to_string(),
+                workflow: Some("ci.yml".to_string()),
+                remote: None,
+                timeout_secs: Some(600),
+            },
+        ]
+    }
+
+    #[test]
+    fn rust_types_match_schema() {
+        let schema: serde_json::Value = serde_json::from_str(SCHEMA).expect("schema json");
+
+        for command in sample_commands() {
+            let value = serde_json::to_value(&command).expect("serialize command");
+            let action = value["action"].as_str().expect("action tag").to_string();
+            assert_eq!(
+                value_fields(value, &["action"]),
+                schema_fields(&schema, &format!("/types/CommandRequest/variants/{action}")),
+                "fields for {action}"
+            );
+        }
+
+        let job = JobRecord {
+            job_id: "job".to_string(),
+            status: JobStatus::Queued,
+            request: sample_commands().remove(0),
+            result: None,
+            error: None,
+            created_at_ms: 1,
+            started_at_ms: None,
+            finished_at_ms: None,
+        };
+        let samples = [
+            (
+                "HealthResponse",
+                serde_json::to_value(HealthResponse {
+                    status: "ok".to_string(),
+                    bind: "127.0.0.1:47631".to_string(),
+                    queued_jobs: 0,
+                }),
+            ),
+            (
+                "WorkspaceSnapshot",
+                serde_json::to_value(WorkspaceSnapshot {
+                    workspace_id: "ws".to_string(),
+                    name: "Main".to_string(),
+                    repo_root: "/repo".to_string(),
+                    worktree_path: "/repo".to_string(),
+                    runtime_pane_ids: Vec::new(),
+                }),
+            ),
+            (
+                "SubmitCommandResponse",
+                serde_json::to_value(SubmitCommandResponse {
+                    job_id: "job".to_string(),
+                    status: JobStatus::Queued,
+                }),
+            ),
+            ("JobRecord", serde_json::to_value(job)),
+            (
+                "DiscoveryRecord",
+                serde_json::to_value(DiscoveryRecord {
+                    bind: "[::1]:47631".to_string(),
+                    pid: 1,
+                    token_required: false,
+                    started_at_ms: 1,
+                }),
+            ),
+        ];
+        for (name, value) in samples {
+            assert_eq!(
+                value_fields(value.expect("serialize sample"), &[]),
+                schema_fields(&schema, &format!("/types/{name}/fields")),
+                "fields for {name}"
+            );
+        }
+
+        let statuses = schema["types"]["JobStatus"]["values"].clone();
+        assert_eq!(
+            serde_json::to_value([
+                JobStatus::Queued,
+                JobStatus::Running,
+                JobStatus::Succeeded,
+                JobStatus::Failed
+            ])
+            .expect("serialize statuses"),
+            statuses
+        );
+    }
+
+    #[test]
+    fn client_sends_token_and_decodes_responses() {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind test listener");
+        let bind = listener.local_addr().expect("local addr").to_string();
+        let server = thread::spawn(move || {
+            let mut requests = Vec::new();
+            for (status, body) in [
+                ("202 Accepted", r#"{"jobId":"job-1","status":"queued"}"#),
+                ("404 Not Found", r#"{"error":"job not found"}"#),
+            ] {
+                let (mut stream, _) = listener.accept().expect("accept");
+                let mut buffer = [0_u8; 4096];
+                let read = stream.read(&mut buffer).expect("read request");
+                requests.push(String::from_utf8_lossy(&buffer[..read]).to_string());
+                let response = format!(
+                    "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                stream
+                    .write_all(response.as_bytes())
+                    .expect("write response");
+            }
+            requests
+        });
+
+        let client = Client::new(bind).with_token("secret");
+        let submitted = client
+            .submit_command(&CommandRequest::CreatePanes {
+                workspace_id: "ws".to_string(),
+                pane_count: 2,
+            })
+            .expect("submit command");
+        assert_eq!(submitted.job_id, "job-1");
+        assert_eq!(submitted.status, JobStatus::Queued);
+
+        match client.get_job("missing") {
+            Err(Error::Http { status, message }) => {
+                assert_eq!(status, 404);
+                assert_eq!(message, "job not found");
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+
+        let requests = server.join().expect("server thread");
+        assert!(requests[0].starts_with("POST /v1/commands HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Authorization: Bearer secret\r\n"));
+        assert!(requests[0]
+            .ends_with(r#"{"action":"create_panes","workspace_id":"ws","pane_count":2}"#));
+        assert!(requests[1].starts_with("GET /v1/jobs/missing HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn parse_port_range_rejects_inverted_bounds() {
+        assert_eq!(parse_port_range("47700-47710"), Some((47700, 47710)));
+        assert_eq!(parse_port_range("47710-47700"), None);
+        assert_eq!(parse_port_range("0-1"), None);
+    }
+
+    #[test]
+    fn discovery_record_requires_a_private_file_and_loopback_bind() {
+        let dir = env::temp_dir().join(format!(
+            "supervibing-client-discovery-{}",
+            std::process::id()
+        ));
+        fs::create_dir_all(&dir).expect("create temp dir");
+        let path = dir.join(DISCOVERY_FILE_NAME);
+        let write = |bind: &str| {
+            let record = DiscoveryRecord {
+                bind: bind.to_string(),
+                pid: 1,
+                token_required: true,
+                started_at_ms: 0,
+            };
+            fs::write(&path, serde_json::to_vec(&record).expect("encode")).expect("write");
+        };
+
+        write("127.0.0.1:47631");
+        let loopback = read_discovery_record_at(&path).map(|record| record.bind);
+        write("[::1]:47631");
+        let ipv6 = read_discovery_record_at(&path).map(|record| record.bind);
+        write("203.0.113.7:47631");
+        let remote = read_discovery_record_at(&path);
+        write("localhost:47631");
+        let hostname = read_discovery_record_at(&path);
+        #[cfg(unix)]
+        let shared = {
+            use std::os::unix::fs::PermissionsExt;
+            write("127.0.0.1:47631");
+            fs::set_permissions(&path, fs::Permissions::from_mode(0o666)).expect("chmod");
+            read_discovery_record_at(&path)
+        };
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(loopback.as_deref(), Some("127.0.0.1:47631"));
+        assert_eq!(ipv6.as_deref(), Some("[::1]:47631"));
+        assert!(remote.is_none());
+        assert!(hostname.is_none());
+        #[cfg(unix)]
+        assert!(shared.is_none());
+    }
+}
@@ -0,0 +1,239 @@
+#!/usr/bin/env node
+// Generates src/index.ts from the automation bridge schema shared with the
+// `supervibing-client` Rust crate. Run with `pnpm --filter @supervibing/automation-client generate`.
+import { readFileSync, writeFileSync } from "node:fs";
+import { dirname, resolve } from "node:path";
+import { fileURLToPath } from "node:url";
+
+const packageRoot = resolve(dirname(fileURLToPath(import.meta.url)), "..");
+const schemaPath = resolve(packageRoot, "../../crates/supervibing-client/schema/automation-v1.json");
+const outputPath = resolve(packageRoot, "src/index.ts");
+
+const schema = JSON.parse(readFileSync(schemaPath, "utf8"));
+
+function tsType(type) {
+  if (type.endsWith("[]")) {
+    return `${tsType(type.slice(0, -2))}[]`;
+  }
+  const mapMatch = /^map<(.+)>$/.exec(type);
+  if (mapMatch) {
+    return `Record<string, ${tsType(mapMatch[1])}>`;
+  }
+  switch (type) {
+    case "string":
+      return "string";
+    case "integer":
+      return "number";
+    case "boolean":
+      return "boolean";
+    case "json":
+      return "unknown";
+    default:
+      if (!schema.types[type]) {
+        throw new Error(`unknown schema type: ${type}`);
+      }
+      return type;
+  }
+}
+
+function renderFields(fields, indent) {
+  return Object.entries(fields)
+    .map(([name, field]) =>
+      field.optional
+        ? `${indent}${name}?: ${tsType(field.type)} | null;`
+        : `${indent}${name}: ${tsType(field.type)};`,
+    )
+    .join("\n");
+}
+
+function renderType(name, definition) {
+  switch (definition.kind) {
+    case "object":
+      return `export interface ${name} {\n${renderFields(definition.fields, "  ")}\n}`;
+    case "enum":
+      return `export type ${name} = ${definition.values.map((value) => JSON.stringify(value)).join(" | ")};`;
+    case "union": {
+      const variants = Object.entries(definition.variants).map(
+        ([tag, fields]) =>
+          `  | {\n      ${definition.tag}: ${JSON.stringify(tag)};\n${renderFields(fields, "      ")}\n    }`,
+      );
+      return `export type ${name} =\n${variants.join("\n")};`;
+    }
+    default:
+      throw new Error(`unsupported schema kind for ${name}: ${definition.kind}`);
+  }
+}
+
+function renderEndpoint(endpoint) {
+  const params = [...endpoint.path.matchAll(/\{(\w+)\}/g)].map((match) => match[1]);
+  const args = [
+    ...params.map((param) => `${param}: string`),
+    ...(endpoint.body ? [`body: ${endpoint.body}`] : []),
+  ];
+  const path = endpoint.path.replace(/\{(\w+)\}/g, (_, param) => `\${encodeURIComponent(${param})}`);
+  const body = endpoint.body ? ", body" : "";
+  return [
+    `  ${endpoint.name}(${args.join(", ")}): Promise<${endpoint.response}> {`,
+    `    return this.request<${endpoint.response}>(${JSON.stringify(endpoint.method)}, \`${path}\`${body});`,
+    "  }",
+  ].join("\n");
+}
+
+const discovery = schema.discovery;
+const [defaultPortStart] = discovery.defaultPortRange;
+
+const output = `// Generated by scripts/generate.mjs from crates/supervibing-client/schema/automation-v1.json.
+// Do not edit by hand.
+
+export const AUTOMATION_SCHEMA_VERSION = ${schema.version};
+export const AUTOMATION_BIND_ENV = ${JSON.stringify(discovery.bindEnv)};
+export const AUTOMATION_TOKEN_ENV = ${JSON.stringify(discovery.tokenEnv)};
+export const AUTOMATION_DISCOVERY_FILE_ENV = ${JSON.stringify(discovery.discoveryFileEnv)};
+export const AUTOMATION_DISCOVERY_FILE_NAME = ${JSON.stringify(discovery.discoveryFileName)};
+export const DEFAULT_AUTOMATION_BIND = ${JSON.stringify(`${discovery.defaultHost}:${defaultPortStart}`)};
+
+${Object.entries(schema.types)
+  .map(([name, definition]) => renderType(name, definition))
+  .join("\n\n")}
+
+export class AutomationError extends Error {
+  constructor(
+    message: string,
+    readonly status?: number,
+  ) {
+    super(message);
+    this.name = "AutomationError";
+  }
+}
+
+export interface AutomationClientOptions {
+  bind?: string;
+  token?: string | null;
+  fetch?: typeof fetch;
+}
+
+export interface WaitForJobOptions {
+  timeoutMs?: number;
+  pollIntervalMs?: number;
+}
+
+type NodeEnv = Record<string, string | undefined>;
+
+function processEnv(): NodeEnv {
+  const candidate = (globalThis as { process?: { env?: NodeEnv } }).process;
+  return candidate?.env ?? {};
+}
+
+/**
+ * Resolves the bridge bind the same way the Rust client does: explicit env bind,
+ * then the discovery file written by the desktop app, then the default bind.
+ */
+export async function discoverBind(env: NodeEnv = processEnv()): Promise<string> {
+  const explicit = env[AUTOMATION_BIND_ENV]?.trim();
+  if (explicit) {
+    return explicit;
+  }
+
+  try {
+    const [{ lstat, readFile }, { tmpdir }, { join }] = await Promise.all([
+      import("node:fs/promises"),
+      import("node:os"),
+      import("node:path"),
+    ]);
+    const path =
+      env[AUTOMATION_DISCOVERY_FILE_ENV]?.trim() ||
+      join(env.XDG_RUNTIME_DIR?.trim() || tmpdir(), AUTOMATION_DISCOVERY_FILE_NAME);
+    // The file may live in a shared temp dir: only trust a regular file owned by this user
+    // that nobody else can write (the ownership checks only apply where uids exist).
+    const info = await lstat(path);
+    const uid = (globalThis as { process?: { getuid?: () => number } }).process?.getuid?.();
+    const trusted =
+      info.isFile() && (uid === undefined || (info.uid === uid && (info.mode & 0o022) === 0));
+    if (trusted) {
+      const record = JSON.parse(await readFile(path, "utf8")) as DiscoveryRecord;
+      if (record.bind && isLoopbackBind(record.bind)) {
+        return record.bind;
+      }
+    }
+  } catch {
+    // Missing or unreadable discovery file falls back to the default bind.
+  }
+
+  return DEFAULT_AUTOMATION_BIND;
+}
+
+function isLoopbackBind(bind: string): boolean {
+  const match = /^(?:\\[([^\\]]+)\\]|([^:]+)):\\d+$/.exec(bind.trim());
+  const host = match?.[1] ?? match?.[2];
+  return host === "::1" || /^127\\.\\d{1,3}\\.\\d{1,3}\\.\\d{1,3}$/.test(host ?? "");
+}
+
+export class AutomationClient {
+  readonly bind: string;
+  private readonly token: string | null;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: AutomationClientOptions = {}) {
+    this.bind = options.bind ?? DEFAULT_AUTOMATION_BIND;
+    this.token = options.token ?? null;
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  static async discover(options: Omit<AutomationClientOptions, "bind"> = {}): Promise<AutomationClient> {
+    const env = processEnv();
+    return new AutomationClient({
+      ...options,
+      bind: await discoverBind(env),
+      token: options.token ?? (env[AUTOMATION_TOKEN_ENV]?.trim() || null),
+    });
+  }
+
+${schema.endpoints.map(renderEndpoint).join("\n\n")}
+
+  async waitForJob(jobId: string, options: WaitForJobOptions = {}): Promise<JobRecord> {
+    const timeoutMs = options.timeoutMs ?? 60_000;
+    const pollIntervalMs = options.pollIntervalMs ?? 250;
+    const deadline = Date.now() + timeoutMs;
+    for (;;) {
+      const job = await this.getJob(jobId);
+      if (job.status === "succeeded" || job.status === "failed") {
+        return job;
+      }
+      if (Date.now() >= deadline) {
+        throw new AutomationError(\`timed out waiting for job \${jobId}\`);
+      }
+      await new Promise((resolveDelay) => setTimeout(resolveDelay, pollIntervalMs));
+    }
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const headers: Record<string, string> = { accept: "application/json" };
+    if (body !== undefined) {
+      headers["content-type"] = "application/json";
+    }
+    if (this.token) {
+      headers.authorization = \`Bearer \${this.token}\`;
+    }
+
+    const response = await this.fetchImpl(\`http://\${this.bind}\${path}\`, {
+      method,
+      headers,
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    if (!response.ok) {
+      let message = text || response.statusText;
+      try {
+        message = (JSON.parse(text) as ErrorResponse).error ?? message;
+      } catch {
+        // Non-JSON error bodies are reported verbatim.
+      }
+      throw new AutomationError(message, response.status);
+    }
+    return JSON.parse(text) as T;
+  }
+}
+`;
+
+writeFileSync(outputPath, output);
+console.log(`wrote ${outputPath}`);
@@ -0,0 +1,253 @@
+// Generated by scripts/generate.mjs from crates/supervibing-client/schema/automation-v1.json.
+// Do not edit by hand.
+
+export const AUTOMATION_SCHEMA_VERSION = 1;
+export const AUTOMATION_BIND_ENV = "SUPERVIBING_AUTOMATION_BIND";
+export const AUTOMATION_TOKEN_ENV = "SUPERVIBING_AUTOMATION_TOKEN";
+export const AUTOMATION_DISCOVERY_FILE_ENV = "SUPERVIBING_AUTOMATION_DISCOVERY_FILE";
+export const AUTOMATION_DISCOVERY_FILE_NAME = "supervibing-automation.json";
+export const DEFAULT_AUTOMATION_BIND = "127.0.0.1:47631";
+
+export interface HealthResponse {
+  status: string;
+  bind: string;
+  queuedJobs: number;
+}
+
+export interface WorkspaceSnapshot {
+  workspaceId: string;
+  name: string;
+  repoRoot: string;
+  worktreePath: string;
+  runtimePaneIds: string[];
+}
+
+export interface WorkspacesResponse {
+  workspaces: WorkspaceSnapshot[];
+}
+
+export type JobStatus = "queued" | "running" | "succeeded" | "failed";
+
+export type WorktreeCreateMode = "newBranch" | "existingBranch" | "remoteBranch";
+
+export type CommandRequest =
+  | {
+      action: "create_panes";
+      workspace_id: string;
+      pane_count: number;
+    }
+  | {
+      action: "create_worktree";
+      workspace_id: string;
+      mode: WorktreeCreateMode;
+      branch: string;
+      base_ref?: string | null;
+      open_after_create?: boolean | null;
+    }
+  | {
+      action: "create_branch";
+      workspace_id: string;
+      branch: string;
+      base_ref?: string | null;
+      checkout?: boolean | null;
+    }
+  | {
+      action: "run_command";
+      workspace_id: string;
+      command: string;
+      execute?: boolean | null;
+    }
+  | {
+      action: "scaffold_file";
+      workspace_id: string;
+      template: string;
+      target_path: string;
+      variables?: Record<string, string> | null;
+      stage?: boolean | null;
+    }
+  | {
+      action: "run_ci_and_wait";
+      workspace_id: string;
+      workflow?: string | null;
+      remote?: string | null;
+      timeout_secs?: number | null;
+    };
+
+export interface SubmitCommandResponse {
+  jobId: string;
+  status: JobStatus;
+}
+
+export interface JobRecord {
+  jobId: string;
+  status: JobStatus;
+  request: CommandRequest;
+  result?: unknown | null;
+  error?: string | null;
+  createdAtMs: number;
+  startedAtMs?: number | null;
+  finishedAtMs?: number | null;
+}
+
+export interface DiscoveryRecord {
+  bind: string;
+  pid: number;
+  tokenRequired: boolean;
+  startedAtMs: number;
+}
+
+export interface ErrorResponse {
+  error: string;
+}
+
+export class AutomationError extends Error {
+  constructor(
+    message: string,
+    readonly status?: number,
+  ) {
+    super(message);
+    this.name = "AutomationError";
+  }
+}
+
+export interface AutomationClientOptions {
+  bind?: string;
+  token?: string | null;
+  fetch?: typeof fetch;
+}
+
+export interface WaitForJobOptions {
+  timeoutMs?: number;
+  pollIntervalMs?: number;
+}
+
+type NodeEnv = Record<string, string | undefined>;
+
+function processEnv(): NodeEnv {
+  const candidate = (globalThis as { process?: { env?: NodeEnv } }).process;
+  return candidate?.env ?? {};
+}
+
+/**
+ * Resolves the bridge bind the same way the Rust client does: explicit env bind,
+ * then the discovery file written by the desktop app, then the default bind.
+ */
+export async function discoverBind(env: NodeEnv = processEnv()): Promise<string> {
+  const explicit = env[AUTOMATION_BIND_ENV]?.trim();
+  if (explicit) {
+    return explicit;
+  }
+
+  try {
+    const [{ lstat, readFile }, { tmpdir }, { join }] = await Promise.all([
+      import("node:fs/promises"),
+      import("node:os"),
+      import("node:path"),
+    ]);
+    const path =
+      env[AUTOMATION_DISCOVERY_FILE_ENV]?.trim() ||
+      join(env.XDG_RUNTIME_DIR?.trim() || tmpdir(), AUTOMATION_DISCOVERY_FILE_NAME);
+    // The file may live in a shared temp dir: only trust a regular file owned by this user
+    // that nobody else can write (the ownership checks only apply where uids exist).
+    const info = await lstat(path);
+    const uid = (globalThis as { process?: { getuid?: () => number } }).process?.getuid?.();
+    const trusted =
+      info.isFile() && (uid === undefined || (info.uid === uid && (info.mode & 0o022) === 0));
+    if (trusted) {
+      const record = JSON.parse(await readFile(path, "utf8")) as DiscoveryRecord;
+      if (record.bind && isLoopbackBind(record.bind)) {
+        return record.bind;
+      }
+    }
+  } catch {
+    // Missing or unreadable discovery file falls back to the default bind.
+  }
+
+  return DEFAULT_AUTOMATION_BIND;
+}
+
+function isLoopbackBind(bind: string): boolean {
+  const match = /^(?:\[([^\]]+)\]|([^:]+)):\d+$/.exec(bind.trim());
+  const host = match?.[1] ?? match?.[2];
+  return host === "::1" || /^127\.\d{1,3}\.\d{1,3}\.\d{1,3}$/.test(host ?? "");
+}
+
+export class AutomationClient {
+  readonly bind: string;
+  private readonly token: string | null;
+  private readonly fetchImpl: typeof fetch;
+
+  constructor(options: AutomationClientOptions = {}) {
+    this.bind = options.bind ?? DEFAULT_AUTOMATION_BIND;
+    this.token = options.token ?? null;
+    this.fetchImpl = options.fetch ?? globalThis.fetch.bind(globalThis);
+  }
+
+  static async discover(options: Omit<AutomationClientOptions, "bind"> = {}): Promise<AutomationClient> {
+    const env = processEnv();
+    return new AutomationClient({
+      ...options,
+      bind: await discoverBind(env),
+      token: options.token ?? (env[AUTOMATION_TOKEN_ENV]?.trim() || null),
+    });
+  }
+
+  health(): Promise<HealthResponse> {
+    return this.request<HealthResponse>("GET", `/v1/health`);
+  }
+
+  workspaces(): Promise<WorkspacesResponse> {
+    return this.request<WorkspacesResponse>("GET", `/v1/workspaces`);
+  }
+
+  submitCommand(body: CommandRequest): Promise<SubmitCommandResponse> {
+    return this.request<SubmitCommandResponse>("POST", `/v1/commands`, body);
+  }
+
+  getJob(jobId: string): Promise<JobRecord> {
+    return this.request<JobRecord>("GET", `/v1/jobs/${encodeURIComponent(jobId)}`);
+  }
+
+  async waitForJob(jobId: string, options: WaitForJobOptions = {}): Promise<JobRecord> {
+    const timeoutMs = options.timeoutMs ?? 60_000;
+    const pollIntervalMs = options.pollIntervalMs ?? 250;
+    const deadline = Date.now() + timeoutMs;
+    for (;;) {
+      const job = await this.getJob(jobId);
+      if (job.status === "succeeded" || job.status === "failed") {
+        return job;
+      }
+      if (Date.now() >= deadline) {
+        throw new AutomationError(`timed out waiting for job ${jobId}`);
+      }
+      await new Promise((resolveDelay) => setTimeout(resolveDelay, pollIntervalMs));
+    }
+  }
+
+  private async request<T>(method: string, path: string, body?: unknown): Promise<T> {
+    const headers: Record<string, string> = { accept: "application/json" };
+    if (body !== undefined) {
+      headers["content-type"] = "application/json";
+    }
+    if (this.token) {
+      headers.authorization = `Bearer ${this.token}`;
+    }
+
+    const response = await this.fetchImpl(`http://${this.bind}${path}`, {
+      method,
+      headers,
+      body: body === undefined ? undefined : JSON.stringify(body),
+    });
+    const text = await response.text();
+    if (!response.ok) {
+      let message = text || response.statusText;
+      try {
+        message = (JSON.parse(text) as ErrorResponse).error ?? message;
+      } catch {
+        // Non-JSON error bodies are reported verbatim.
+      }
+      throw new AutomationError(message, response.status);
+    }
+    return JSON.parse(text) as T;
+  }
+}